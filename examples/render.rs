@@ -65,10 +65,11 @@ impl SimpleSurface {
     fn write_pgm(&self, filename: &str) -> Result<(), std::io::Error> {
         let mut f = File::create(filename)?;
         write!(f, "P5\n{} {}\n255\n", self.width, self.height)?;
-        f.write(&self.pixels)?;
+        f.write_all(&self.pixels)?;
         Ok(())
     }
 
+    #[allow(unused)]
     fn paint_layout(&mut self, layout: &Layout, x: i32, y: i32) {
         for glyph in &layout.glyphs {
             let glyph_id = glyph.glyph_id;
@@ -211,7 +212,10 @@ fn main() {
     let data = font.copy_font_data();
     println!("font data: {:?} bytes", data.map(|d| d.len()));
 
-    let style = TextStyle { size: 32.0 };
+    let style = TextStyle {
+        size: 32.0,
+        ..Default::default()
+    };
     let glyph_id = font.glyph_for_char('O').unwrap();
     println!("glyph id = {}", glyph_id);
     println!(
@@ -255,7 +259,6 @@ fn main() {
     let text = args
         .next()
         .unwrap_or("Hello हिन्दी".to_string());
-    //let layout = make_layout(&style, &font, &text);
     let collection = make_collection();
     /*
     let layout = layout(&style, &collection, &text);
@@ -13,17 +13,18 @@ def gen_decomp(unicode_data_fn):
                 print('codepoint {:x} maps to itself')
             if len(decomp) == 1: decomp.append(0)
             decomps.append((cp, decomp))
-    print('pub const CANONICAL_DECOMP_KEY: [u32; {}] = ['.format(len(decomps)))
+    print('pub static CANONICAL_DECOMP_KEY: [u32; {}] = ['.format(len(decomps)))
     for (cp, decomp) in decomps:
         print('    0x{:x},'.format(cp))
     print('];')
-    print('pub const CANONICAL_DECOMP_VAL: [(u32, u32); {}] = ['.format(len(decomps)))
+    print('pub static CANONICAL_DECOMP_VAL: [(u32, u32); {}] = ['.format(len(decomps)))
     for (cp, decomp) in decomps:
         print('    (0x{:x}, 0x{:x}),'.format(decomp[0], decomp[1]))
     print('];')
 
-# This list was adapted from harfbuzz_sys/lib.rs (as of 0.3.1). It will probably need to
-# be updated for future Harfbuzz releases, as it's missing some scripts in Unicode 12.
+# This list was adapted from harfbuzz_sys/lib.rs (as of 0.5), and matches the constants
+# in src/script.rs. It will need to be updated for future HarfBuzz releases, as it's
+# missing the scripts added after Unicode 13.
 hb_scripts = set((
     'COMMON',
     'INHERITED',
@@ -174,6 +175,14 @@ hb_scripts = set((
     'MEDEFAIDRIN',
     'OLD_SOGDIAN',
     'SOGDIAN',
+    'ELYMAIC',
+    'NANDINAGARI',
+    'NYIAKENG_PUACHUE_HMONG',
+    'WANCHO',
+    'CHORASMIAN',
+    'DIVES_AKURU',
+    'KHITAN_SMALL_SCRIPT',
+    'YEZIDI',
 ))
 
 def gen_script(script_data_fn):
@@ -190,7 +199,7 @@ def gen_script(script_data_fn):
         else:
             cp_end = cp_start + 1
         script = s[1].split('#')[0].strip()
-        if len(scripts) and scripts[-1][1] == cp_start:
+        if len(scripts) and scripts[-1][1] == cp_start and scripts[-1][2] == script:
             scripts[-1][1] = cp_end
         else:
             scripts.append([cp_start, cp_end, script])
@@ -202,21 +211,60 @@ def gen_script(script_data_fn):
         if hb_script_name == 'CANADIAN_ABORIGINAL':
             hb_script_name = 'CANADIAN_SYLLABICS'
         if not hb_script_name in hb_scripts:
-            if script in ('Elymaic', 'Nandinagari', 'Nyiakeng_Puachue_Hmong', 'Wancho'):
-                if script not in warned_scripts:
-                    print('// Warning: script {} not known by HarfBuzz'.format(script))
-                    warned_scripts.add(script)
-                continue
-            print("Unknown script {}".format(script), file = sys.stderr)
-            exit(1)
+            if script not in warned_scripts:
+                print('// Warning: script {} not known by HarfBuzz'.format(script))
+                warned_scripts.add(script)
+            continue
         hb_data.append((cp_start, cp_end, script, hb_script_name))
-    print('pub const SCRIPT_KEY: [(u32, u32); {}] = ['.format(len(hb_data)))
+    print('pub static SCRIPT_KEY: [(u32, u32); {}] = ['.format(len(hb_data)))
     for (cp_start, cp_end, script, hb_script_name) in hb_data:
         print('    (0x{:x}, 0x{:x}), // {}'.format(cp_start, cp_end, script))
     print('];')
-    print('pub const SCRIPT_VAL: [hb_script_t; {}] = ['.format(len(hb_data)))
+    print('pub static SCRIPT_VAL: [Script; {}] = ['.format(len(hb_data)))
     for (cp_start, cp_end, script, hb_script_name) in hb_data:
-        print('    HB_SCRIPT_{},'.format(hb_script_name))
+        print('    SCRIPT_{},'.format(hb_script_name))
+    print('];')
+
+def gen_script_extensions(aliases_fn, scx_data_fn):
+    short_names = {}
+    for line in open(aliases_fn):
+        s = [f.strip() for f in line.split('#')[0].split(';')]
+        if len(s) >= 3 and s[0] == 'sc':
+            short_names[s[1]] = s[2]
+    exts = []
+    warned_scripts = set()
+    for line in open(scx_data_fn):
+        line = line.split('#')[0].rstrip()
+        if line == '':
+            continue
+        s = line.split(';')
+        cp_range = s[0].rstrip().split('..')
+        cp_start = int(cp_range[0], 16)
+        if len(cp_range) == 2:
+            cp_end = int(cp_range[1], 16) + 1
+        else:
+            cp_end = cp_start + 1
+        hb_script_names = []
+        for short_name in s[1].split():
+            script = short_names[short_name]
+            hb_script_name = script.upper()
+            if hb_script_name == 'CANADIAN_ABORIGINAL':
+                hb_script_name = 'CANADIAN_SYLLABICS'
+            if hb_script_name in hb_scripts:
+                hb_script_names.append(hb_script_name)
+            elif script not in warned_scripts:
+                print('// Warning: script {} not known by HarfBuzz'.format(script))
+                warned_scripts.add(script)
+        if hb_script_names:
+            exts.append((cp_start, cp_end, hb_script_names))
+    exts.sort()
+    print('pub static SCRIPT_EXT_KEY: [(u32, u32); {}] = ['.format(len(exts)))
+    for (cp_start, cp_end, hb_script_names) in exts:
+        print('    (0x{:x}, 0x{:x}),'.format(cp_start, cp_end))
+    print('];')
+    print("pub static SCRIPT_EXT_VAL: [&[Script]; {}] = [".format(len(exts)))
+    for (cp_start, cp_end, hb_script_names) in exts:
+        print('    &[{}],'.format(', '.join('SCRIPT_' + n for n in hb_script_names)))
     print('];')
 
 def gen_mirroring(mirror_data_fn):
@@ -228,15 +276,75 @@ def gen_mirroring(mirror_data_fn):
             continue
         fr, to = [int(cp.strip(), 16) for cp in line.split(';')]
         mirrors.append((fr, to))
-    print('pub const MIRROR_KEY: [u32; {}] = ['.format(len(mirrors)))
+    print('pub static MIRROR_KEY: [u32; {}] = ['.format(len(mirrors)))
     for (fr, to) in mirrors:
         print('    0x{:x}, // -> 0x{:x}'.format(fr, to))
     print('];')
-    print('pub const MIRROR_VAL: [u32; {}] = ['.format(len(mirrors)))
+    print('pub static MIRROR_VAL: [u32; {}] = ['.format(len(mirrors)))
     for (fr, to) in mirrors:
         print('    0x{:x}, // <- 0x{:x}'.format(to, fr))
     print('];')
 
+def gen_brackets(brackets_fn):
+    brackets = []
+    for line in open(brackets_fn):
+        line = line.split('#')[0]
+        line = line.rstrip()
+        if line == '':
+            continue
+        cp, pair, kind = [f.strip() for f in line.split(';')]
+        cp = int(cp, 16)
+        pair = int(pair, 16)
+        opening = cp if kind == 'o' else pair
+        brackets.append((cp, opening, kind))
+    brackets.sort()
+    print('pub static PAIRED_BRACKET_KEY: [u32; {}] = ['.format(len(brackets)))
+    for (cp, opening, kind) in brackets:
+        print('    0x{:x}, // {}'.format(cp, kind))
+    print('];')
+    # Brackets are identified by the opening bracket of their pair.
+    print('pub static PAIRED_BRACKET_VAL: [u32; {}] = ['.format(len(brackets)))
+    for (cp, opening, kind) in brackets:
+        print('    0x{:x}, // <- 0x{:x}'.format(opening, cp))
+    print('];')
+
+def gen_vertical_orientation(vo_data_fn):
+    names = {
+        'U': 'Upright',
+        'R': 'Rotated',
+        'Tu': 'TransformedUpright',
+        'Tr': 'TransformedRotated',
+    }
+    vos = []
+    for line in open(vo_data_fn):
+        line = line.split('#')[0].rstrip()
+        if line == '':
+            continue
+        s = line.split(';')
+        cp_range = s[0].rstrip().split('..')
+        cp_start = int(cp_range[0], 16)
+        if len(cp_range) == 2:
+            cp_end = int(cp_range[1], 16) + 1
+        else:
+            cp_end = cp_start + 1
+        vo = s[1].strip()
+        # Rotated is the default, so it isn't stored.
+        if vo == 'R':
+            continue
+        if len(vos) and vos[-1][1] == cp_start and vos[-1][2] == vo:
+            vos[-1][1] = cp_end
+        else:
+            vos.append([cp_start, cp_end, vo])
+    vos.sort()
+    print('pub static VERTICAL_ORIENTATION_KEY: [(u32, u32); {}] = ['.format(len(vos)))
+    for (cp_start, cp_end, vo) in vos:
+        print('    (0x{:x}, 0x{:x}),'.format(cp_start, cp_end))
+    print('];')
+    print('pub static VERTICAL_ORIENTATION_VAL: [VerticalOrientation; {}] = ['.format(len(vos)))
+    for (cp_start, cp_end, vo) in vos:
+        print('    VerticalOrientation::{},'.format(names[vo]))
+    print('];')
+
 def gen_copyright(readme_fn):
     for line in open(readme_fn):
         line = line.rstrip()
@@ -252,10 +360,15 @@ def main(args):
 """)
     gen_copyright(os.path.join(ucd_dir, 'Readme.txt'))
     print('')
-    print('use harfbuzz::sys::*;')
+    print('use crate::script::*;')
+    print('use crate::vertical::VerticalOrientation;')
     print('')
     gen_decomp(os.path.join(ucd_dir, 'UnicodeData.txt'))
     gen_script(os.path.join(ucd_dir, 'Scripts.txt'))
+    gen_script_extensions(os.path.join(ucd_dir, 'PropertyValueAliases.txt'),
+        os.path.join(ucd_dir, 'ScriptExtensions.txt'))
     gen_mirroring(os.path.join(ucd_dir, 'BidiMirroring.txt'))
+    gen_brackets(os.path.join(ucd_dir, 'BidiBrackets.txt'))
+    gen_vertical_orientation(os.path.join(ucd_dir, 'VerticalOrientation.txt'))
 
 main(sys.argv)
@@ -0,0 +1,300 @@
+//! Construction of a font collection from Android's fonts.xml.
+//!
+//! Android describes its fonts, including the language and variant of each
+//! fallback family, in /system/etc/fonts.xml. Before Lollipop the same
+//! information was split between system_fonts.xml and fallback_fonts.xml, in
+//! a different format. The parsing logic follows Skia's
+//! SkFontMgr_android_parser; see docs/script_matching.md.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{FamilyVariant, Font, FontCollection, FontFamily, FontRef};
+
+/// Metadata for one family, as described in the config file.
+#[derive(Clone, Debug, Default)]
+pub struct FamilyConfig {
+    /// Names of the family, including aliases. Fallback families are unnamed.
+    pub names: Vec<String>,
+    /// BCP-47 language tags the family is intended for.
+    pub lang: Vec<String>,
+    pub variant: FamilyVariant,
+    pub fonts: Vec<FontConfig>,
+}
+
+/// A single font file within a family.
+#[derive(Clone, Debug)]
+pub struct FontConfig {
+    /// File name, relative to the font directory.
+    pub file: String,
+    /// Face index within a collection file.
+    pub index: u32,
+    pub weight: u16,
+    pub italic: bool,
+}
+
+#[derive(Debug)]
+pub enum FontsXmlError {
+    Io(io::Error),
+    Xml(roxmltree::Error),
+}
+
+impl fmt::Display for FontsXmlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontsXmlError::Io(e) => write!(f, "error reading font config: {}", e),
+            FontsXmlError::Xml(e) => write!(f, "error parsing font config: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for FontsXmlError {}
+
+impl From<io::Error> for FontsXmlError {
+    fn from(e: io::Error) -> FontsXmlError {
+        FontsXmlError::Io(e)
+    }
+}
+
+impl From<roxmltree::Error> for FontsXmlError {
+    fn from(e: roxmltree::Error) -> FontsXmlError {
+        FontsXmlError::Xml(e)
+    }
+}
+
+/// Parse a font config file, in either the Lollipop or the older format.
+///
+/// Families are returned in file order, which is also the fallback order.
+pub fn parse_fonts_xml(xml: &str) -> Result<Vec<FamilyConfig>, FontsXmlError> {
+    let doc = roxmltree::Document::parse(xml)?;
+    let mut families: Vec<FamilyConfig> = Vec::new();
+    let mut aliases = Vec::new();
+    for node in doc.root_element().children().filter(|n| n.is_element()) {
+        match node.tag_name().name() {
+            "family" => {
+                let is_legacy = node.children().any(|n| n.has_tag_name("fileset"));
+                let family = if is_legacy {
+                    parse_legacy_family(node)
+                } else {
+                    parse_family(node)
+                };
+                families.push(family);
+            }
+            "alias" => {
+                if let (Some(name), Some(to)) = (node.attribute("name"), node.attribute("to")) {
+                    // Aliases with a weight select a specific member; we only
+                    // record the plain ones.
+                    if node.attribute("weight").is_none() {
+                        aliases.push((name.to_string(), to.to_string()));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    for (name, to) in aliases {
+        if let Some(family) = families.iter_mut().find(|f| f.names.contains(&to)) {
+            family.names.push(name);
+        }
+    }
+    Ok(families)
+}
+
+// Lollipop and later: <family name lang variant><font weight style index>file</font>
+fn parse_family(node: roxmltree::Node) -> FamilyConfig {
+    let mut family = FamilyConfig::default();
+    if let Some(name) = node.attribute("name") {
+        family.names.push(name.to_string());
+    }
+    if let Some(lang) = node.attribute("lang") {
+        family.lang = lang.split_whitespace().map(String::from).collect();
+    }
+    family.variant = parse_variant(node.attribute("variant"));
+    for font in node.children().filter(|n| n.has_tag_name("font")) {
+        // The file name is the text before any <axis> children.
+        let file = match font.children().find(|n| n.is_text()).and_then(|n| n.text()) {
+            Some(file) if !file.trim().is_empty() => file.trim().to_string(),
+            _ => continue,
+        };
+        family.fonts.push(FontConfig {
+            file,
+            index: parse_attr(font.attribute("index"), 0),
+            weight: parse_attr(font.attribute("weight"), 400),
+            italic: font.attribute("style") == Some("italic"),
+        });
+    }
+    family
+}
+
+// Before Lollipop: <family order><nameset><name/></nameset><fileset><file lang variant/></fileset>
+fn parse_legacy_family(node: roxmltree::Node) -> FamilyConfig {
+    let mut family = FamilyConfig::default();
+    for child in node.children().filter(|n| n.is_element()) {
+        match child.tag_name().name() {
+            "nameset" => {
+                for name in child.children().filter(|n| n.has_tag_name("name")) {
+                    if let Some(text) = name.text() {
+                        family.names.push(text.trim().to_string());
+                    }
+                }
+            }
+            "fileset" => {
+                for file in child.children().filter(|n| n.has_tag_name("file")) {
+                    let text = match file.text() {
+                        Some(text) => text.trim().to_string(),
+                        None => continue,
+                    };
+                    // Language and variant are per file in this format, but in
+                    // practice they're the same for every file of a family.
+                    if let Some(lang) = file.attribute("lang") {
+                        if !family.lang.iter().any(|l| l == lang) {
+                            family.lang.push(lang.to_string());
+                        }
+                    }
+                    if file.attribute("variant").is_some() {
+                        family.variant = parse_variant(file.attribute("variant"));
+                    }
+                    let (weight, italic) = legacy_style_from_file_name(&text);
+                    family.fonts.push(FontConfig {
+                        file: text,
+                        index: parse_attr(file.attribute("index"), 0),
+                        weight,
+                        italic,
+                    });
+                }
+            }
+            _ => (),
+        }
+    }
+    family
+}
+
+// The old format has no style attributes; Skia infers them from the name.
+fn legacy_style_from_file_name(file: &str) -> (u16, bool) {
+    let weight = if file.contains("Bold") { 700 } else { 400 };
+    (weight, file.contains("Italic"))
+}
+
+fn parse_variant(variant: Option<&str>) -> FamilyVariant {
+    match variant {
+        Some("compact") => FamilyVariant::Compact,
+        Some("elegant") => FamilyVariant::Elegant,
+        _ => FamilyVariant::Default,
+    }
+}
+
+fn parse_attr<T: std::str::FromStr>(attr: Option<&str>, default: T) -> T {
+    attr.and_then(|s| s.trim().parse().ok()).unwrap_or(default)
+}
+
+/// Build a collection from parsed family configs.
+///
+/// Font files are loaded from `font_dir`; missing or unreadable files are
+/// skipped, as are families with no loadable fonts.
+pub fn collection_from_configs(families: &[FamilyConfig], font_dir: &Path) -> FontCollection {
+    let mut collection = FontCollection::new();
+    for config in families {
+        let mut family = FontFamily::new();
+        family.set_lang(config.lang.clone());
+        family.set_variant(config.variant);
+        // The config's styles of the members we could load.
+        let mut styles = Vec::new();
+        for font in &config.fonts {
+            let path = font_dir.join(&font.file);
+            match Font::from_path(&path, font.index) {
+                Ok(loaded) => {
+                    family.add_font(FontRef::new(loaded));
+                    styles.push(font);
+                }
+                Err(e) => warn!("skipping {:?}: {:?}", path, e),
+            }
+        }
+        // Like Minikin, trust the config over the fonts' own metadata for
+        // the default member: upright and closest to weight 400.
+        let default_ix = styles
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, font)| (font.italic, (i32::from(font.weight) - 400).abs()));
+        if let Some((ix, _)) = default_ix {
+            family.set_default_font(ix);
+            collection.add_family(family);
+        }
+    }
+    collection
+}
+
+/// Load the system font collection from an Android-style font directory.
+///
+/// `config_dir` is normally /system/etc and `font_dir` /system/fonts. The
+/// Lollipop fonts.xml is used if present; otherwise the older
+/// system_fonts.xml and fallback_fonts.xml are combined, in that order.
+pub fn load_android_fonts(
+    config_dir: &Path,
+    font_dir: &Path,
+) -> Result<FontCollection, FontsXmlError> {
+    let fonts_xml = config_dir.join("fonts.xml");
+    let families = if fonts_xml.exists() {
+        parse_fonts_xml(&fs::read_to_string(fonts_xml)?)?
+    } else {
+        let mut families = parse_fonts_xml(&fs::read_to_string(config_dir.join("system_fonts.xml"))?)?;
+        let fallback_xml = config_dir.join("fallback_fonts.xml");
+        if fallback_xml.exists() {
+            families.extend(parse_fonts_xml(&fs::read_to_string(fallback_xml)?)?);
+        }
+        families
+    };
+    Ok(collection_from_configs(&families, font_dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::fonts_dir;
+    use crate::FamilyVariant;
+
+    fn test_collection(xml: &str) -> FontCollection {
+        let families = parse_fonts_xml(xml).unwrap();
+        collection_from_configs(&families, &fonts_dir())
+    }
+
+    fn family_of(collection: &FontCollection, text: &str) -> String {
+        let (_, font) = collection.itemize(text).next().unwrap();
+        font.font.family_name()
+    }
+
+    #[test]
+    fn default_member() {
+        let collection = test_collection(
+            r#"<familyset>
+                <family name="sans-serif">
+                    <font weight="700" style="normal">Mada-VF.ttf</font>
+                    <font weight="400" style="italic">Mada-VF.ttf</font>
+                    <font weight="400" style="normal">Estedad-VF.ttf</font>
+                </family>
+            </familyset>"#,
+        );
+        // Estedad's default instance is Black and Mada's Medium, but the
+        // config has the last font as the regular one.
+        assert_eq!(family_of(&collection, "a"), "Estedad");
+    }
+
+    #[test]
+    fn variant() {
+        let mut collection = test_collection(
+            r#"<familyset>
+                <family variant="elegant"><font weight="400">Mada-VF.ttf</font></family>
+                <family variant="compact"><font weight="400">Estedad-VF.ttf</font></family>
+            </familyset>"#,
+        );
+        assert_eq!(family_of(&collection, "a"), "Mada");
+        collection.set_variant(FamilyVariant::Compact);
+        assert_eq!(family_of(&collection, "a"), "Estedad");
+        collection.set_variant(FamilyVariant::Elegant);
+        assert_eq!(family_of(&collection, "a"), "Mada");
+        // Only Estedad has presentation forms, so it's used whatever the
+        // variant.
+        assert_eq!(family_of(&collection, "\u{fb57}"), "Estedad");
+    }
+}
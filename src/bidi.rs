@@ -0,0 +1,171 @@
+//! The bidi stage of layout: embedding levels from the Unicode Bidirectional
+//! Algorithm (UAX #9), and visual reordering of runs.
+//!
+//! Level resolution is done by the unicode-bidi crate. Each paragraph is
+//! treated as a single line, as there is no line breaking yet.
+
+use std::ops::Range;
+
+use unicode_bidi::{BidiInfo, Level};
+
+/// The base direction of a paragraph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BaseDirection {
+    /// The direction of the first strong character in the paragraph, or left
+    /// to right if there is none (rules P2 and P3).
+    Auto,
+    LeftToRight,
+    RightToLeft,
+}
+
+/// A paragraph of the text, split into runs of the same embedding level.
+pub(crate) struct BidiParagraph {
+    /// Maximal runs of the same level, in logical order.
+    pub(crate) runs: Vec<(Range<usize>, u8)>,
+}
+
+/// Resolve the embedding levels of the text.
+///
+/// Without a base direction all text is at level 0, in a single paragraph.
+pub(crate) fn bidi_paragraphs(text: &str, base: Option<BaseDirection>) -> Vec<BidiParagraph> {
+    let default_level = match base {
+        None => {
+            return vec![BidiParagraph {
+                runs: vec![(0..text.len(), 0)],
+            }]
+        }
+        Some(BaseDirection::Auto) => None,
+        Some(BaseDirection::LeftToRight) => Some(Level::ltr()),
+        Some(BaseDirection::RightToLeft) => Some(Level::rtl()),
+    };
+    let info = BidiInfo::new(text, default_level);
+    info.paragraphs
+        .iter()
+        .map(|para| {
+            // This applies rule L1 to trailing whitespace and separators.
+            let levels = info.reordered_levels(para, para.range.clone());
+            let mut runs: Vec<(Range<usize>, u8)> = Vec::new();
+            for (ix, c) in text[para.range.clone()].char_indices() {
+                let start = para.range.start + ix;
+                let end = start + c.len_utf8();
+                let level = levels[start].number();
+                match runs.last_mut() {
+                    Some((range, run_level)) if *run_level == level => range.end = end,
+                    _ => runs.push((start..end, level)),
+                }
+            }
+            BidiParagraph { runs }
+        })
+        .collect()
+}
+
+/// The visual order of runs with the given levels, from left to right (rule
+/// L2).
+pub(crate) fn visual_order(levels: &[u8]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+    let max_level = levels.iter().copied().max().unwrap_or(0);
+    let min_odd_level = levels.iter().copied().filter(|l| l & 1 == 1).min();
+    if let Some(min_odd_level) = min_odd_level {
+        for level in (min_odd_level..=max_level).rev() {
+            let mut ix = 0;
+            while ix < levels.len() {
+                if levels[order[ix]] < level {
+                    ix += 1;
+                    continue;
+                }
+                let start = ix;
+                while ix < levels.len() && levels[order[ix]] >= level {
+                    ix += 1;
+                }
+                order[start..ix].reverse();
+            }
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The text and level of each run, by paragraph.
+    fn runs(text: &str, base: BaseDirection) -> Vec<Vec<(&str, u8)>> {
+        bidi_paragraphs(text, Some(base))
+            .into_iter()
+            .map(|para| {
+                para.runs
+                    .into_iter()
+                    .map(|(range, level)| (&text[range], level))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn no_base_direction() {
+        let paragraphs = bidi_paragraphs("abc שלום\ndef", None);
+        assert_eq!(paragraphs.len(), 1);
+        assert_eq!(paragraphs[0].runs, vec![(0..16, 0)]);
+    }
+
+    #[test]
+    fn ltr_in_rtl() {
+        assert_eq!(
+            runs("שלום abc שלום", BaseDirection::RightToLeft),
+            vec![vec![("שלום ", 1), ("abc", 2), (" שלום", 1)]]
+        );
+        assert_eq!(
+            runs("שלום abc", BaseDirection::Auto),
+            vec![vec![("שלום ", 1), ("abc", 2)]]
+        );
+    }
+
+    #[test]
+    fn rtl_in_ltr() {
+        assert_eq!(
+            runs("abc שלום def", BaseDirection::LeftToRight),
+            vec![vec![("abc ", 0), ("שלום", 1), (" def", 0)]]
+        );
+        assert_eq!(
+            runs("abc שלום", BaseDirection::Auto),
+            vec![vec![("abc ", 0), ("שלום", 1)]]
+        );
+    }
+
+    #[test]
+    fn digits_in_arabic() {
+        // European and Arabic-Indic digits are both at level 2, so they read
+        // left to right.
+        assert_eq!(
+            runs("عدد 123 و ١٢٣", BaseDirection::Auto),
+            vec![vec![("عدد ", 1), ("123", 2), (" و ", 1), ("١٢٣", 2)]]
+        );
+    }
+
+    #[test]
+    fn paragraphs() {
+        // Each paragraph finds its own direction, and trailing whitespace
+        // and the separator are at the paragraph level (rule L1).
+        assert_eq!(
+            runs("abc שלום \nשלום abc\n", BaseDirection::Auto),
+            vec![
+                vec![("abc ", 0), ("שלום", 1), (" \n", 0)],
+                vec![("שלום ", 1), ("abc", 2), ("\n", 1)],
+            ]
+        );
+    }
+
+    #[test]
+    fn visual_order_of_levels() {
+        assert_eq!(visual_order(&[]), Vec::<usize>::new());
+        assert_eq!(visual_order(&[0]), [0]);
+        assert_eq!(visual_order(&[0, 1, 0]), [0, 1, 2]);
+        assert_eq!(visual_order(&[0, 1, 1, 0]), [0, 2, 1, 3]);
+        assert_eq!(visual_order(&[1, 2, 1]), [2, 1, 0]);
+        assert_eq!(visual_order(&[1, 2, 2, 1]), [3, 1, 2, 0]);
+        assert_eq!(visual_order(&[0, 1, 2, 1, 0]), [0, 3, 2, 1, 4]);
+        // Only levels from the lowest odd one up are reversed.
+        assert_eq!(visual_order(&[2, 3, 3, 2]), [0, 2, 1, 3]);
+        assert_eq!(visual_order(&[2, 4, 2]), [0, 1, 2]);
+    }
+}
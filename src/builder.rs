@@ -0,0 +1,203 @@
+//! A builder for font collections from font files and data.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::font::{fonts_from_data, FontLoadingError};
+use crate::{Font, FontCollection, FontData, FontFamily, FontRef};
+
+/// Builds a `FontCollection` from font data, files and directories.
+///
+/// Faces are grouped into families by the family name in their name table.
+/// Families appear in the collection in the order their first face was added,
+/// and the members of each family are sorted by style and weight.
+#[derive(Default)]
+pub struct FontCollectionBuilder {
+    families: Vec<FontFamily>,
+    // Index into `families` by name.
+    by_name: HashMap<String, usize>,
+}
+
+const FONT_EXTENSIONS: &[&str] = &["ttf", "otf", "ttc", "otc"];
+
+impl FontCollectionBuilder {
+    pub fn new() -> FontCollectionBuilder {
+        FontCollectionBuilder::default()
+    }
+
+    /// Add a face from font data in memory.
+    pub fn add_bytes(&mut self, data: Arc<Vec<u8>>, index: u32) -> Result<(), FontLoadingError> {
+        let font = Font::from_bytes(data.clone(), index)?;
+        self.add_font(FontRef::with_data(
+            font,
+            FontData::from_arc_vec(data),
+            index,
+        )?);
+        Ok(())
+    }
+
+    /// Add all the faces in font data, sharing it between the faces and the
+    /// shaper.
+    pub fn add_data(&mut self, data: FontData) -> Result<(), FontLoadingError> {
+        for (index, font) in fonts_from_data(&data)?.into_iter().enumerate() {
+            self.add_font(FontRef::with_data(font, data.clone(), index as u32)?);
+        }
+        Ok(())
+    }
+
+    /// Add all the faces in a font file.
+    ///
+    /// The file is read once, and the buffer is shared by all its faces and
+    /// by the shaper.
+    pub fn add_path(&mut self, path: impl AsRef<Path>) -> Result<(), FontLoadingError> {
+        self.add_data(FontData::from_arc_vec(Arc::new(fs::read(path)?)))
+    }
+
+    /// Add all the faces in a font file, mapping it into memory rather than
+    /// reading it.
+    ///
+    /// See `FontData::map_file` for the caveats.
+    #[cfg(feature = "mmap")]
+    pub fn add_mapped_file(&mut self, path: impl AsRef<Path>) -> Result<(), FontLoadingError> {
+        self.add_data(FontData::map_file(path)?)
+    }
+
+    /// Add all font files in a directory and its subdirectories.
+    ///
+    /// Files are visited in path order, so the result doesn't depend on the
+    /// file system. Files and subdirectories that fail to load are skipped
+    /// with a warning; an error is only returned if `dir` can't be read.
+    pub fn add_dir(&mut self, dir: impl AsRef<Path>) -> io::Result<()> {
+        self.add_dir_once(dir.as_ref(), &mut HashSet::new())
+    }
+
+    // Symlinks can make the tree a graph, possibly with cycles, so each
+    // directory is visited once, by its canonical path.
+    fn add_dir_once(&mut self, dir: &Path, visited: &mut HashSet<PathBuf>) -> io::Result<()> {
+        if !visited.insert(dir.canonicalize()?) {
+            return Ok(());
+        }
+        let mut entries: Vec<_> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect();
+        entries.sort();
+        for path in entries {
+            if path.is_dir() {
+                if let Err(e) = self.add_dir_once(&path, visited) {
+                    warn!("skipping {:?}: {}", path, e);
+                }
+            } else if is_font_file(&path) {
+                if let Err(e) = self.add_path(&path) {
+                    warn!("skipping {:?}: {:?}", path, e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Add an already loaded font.
+    pub fn add_font(&mut self, font: FontRef) {
+        let name = font.font.family_name();
+        let families = &mut self.families;
+        let ix = *self.by_name.entry(name).or_insert_with(|| {
+            families.push(FontFamily::new());
+            families.len() - 1
+        });
+        self.families[ix].add_font(font);
+    }
+
+    pub fn build(self) -> FontCollection {
+        let mut collection = FontCollection::new();
+        for mut family in self.families {
+            family.sort_fonts();
+            collection.add_family(family);
+        }
+        collection
+    }
+}
+
+fn is_font_file(path: &Path) -> bool {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) => FONT_EXTENSIONS.iter().any(|e| ext.eq_ignore_ascii_case(e)),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    #[cfg(unix)]
+    use std::os::unix::fs::symlink;
+    use std::process;
+
+    use super::*;
+    use crate::test_util::{font_bytes, font_path, ESTEDAD, MADA};
+
+    #[test]
+    fn with_data_checks_pairing() {
+        let mada = Arc::new(font_bytes(MADA));
+        let estedad = Arc::new(font_bytes(ESTEDAD));
+        let data = FontData::from_arc_vec(mada.clone());
+        let font = || Font::from_bytes(mada.clone(), 0).unwrap();
+        assert!(FontRef::with_data(font(), data.clone(), 0).is_ok());
+        assert!(FontRef::with_data(font(), data.clone(), 1).is_err());
+        let other = FontData::from_arc_vec(estedad);
+        assert!(FontRef::with_data(font(), other, 0).is_err());
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn add_mapped_file() {
+        use crate::test_util::make_collection;
+
+        let path = env::temp_dir().join(format!("skribo-mapped-{}.ttc", process::id()));
+        fs::write(
+            &path,
+            make_collection(&[font_bytes(MADA), font_bytes(ESTEDAD)]),
+        )
+        .unwrap();
+        let mut builder = FontCollectionBuilder::new();
+        builder.add_mapped_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        let fonts: Vec<&FontRef> = builder
+            .families
+            .iter()
+            .map(|family| family.default_font().unwrap())
+            .collect();
+        assert_eq!(fonts.len(), 2);
+        let mapped = fonts[0].data().unwrap().0.as_bytes().as_ptr();
+        for (i, font) in fonts.iter().enumerate() {
+            let (data, index) = font.data().unwrap();
+            assert_eq!(index, i as u32);
+            assert!(format!("{:?}", data).starts_with("FontData(Mmap"));
+            assert_eq!(data.as_bytes().as_ptr(), mapped);
+        }
+        // The loader's buffer is shared between the faces too.
+        #[cfg(not(feature = "font-kit"))]
+        let loader_bytes = |font: &FontRef| font.font.data_and_index().0.as_bytes().as_ptr();
+        #[cfg(feature = "font-kit")]
+        let loader_bytes = |font: &FontRef| font.font.copy_font_data().unwrap().as_ptr();
+        assert_eq!(loader_bytes(fonts[0]), loader_bytes(fonts[1]));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn add_dir_symlink_cycle() {
+        let dir = env::temp_dir().join(format!("skribo-add-dir-{}", process::id()));
+        let sub = dir.join("sub");
+        fs::create_dir_all(&sub).unwrap();
+        fs::copy(font_path(MADA), sub.join(MADA)).unwrap();
+        symlink(&dir, sub.join("loop")).unwrap();
+        symlink(dir.join("missing"), dir.join("dangling")).unwrap();
+
+        let mut builder = FontCollectionBuilder::new();
+        let result = builder.add_dir(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+        result.unwrap();
+        assert_eq!(builder.families.len(), 1);
+        assert_eq!(builder.families[0].fonts().len(), 1);
+    }
+}
@@ -1,25 +1,89 @@
 //! The font collection type.
 
+#[cfg(any(feature = "harfbuzz", feature = "rustybuzz"))]
+use std::cell::OnceCell;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+#[cfg(feature = "font-kit")]
+use font_kit::source::Source;
+
+use crate::coverage::{font_coverage, SparseBitSet};
+use crate::fallback::{locale_for_script, FallbackProvider};
+use crate::font::{data_matches, shared_data, FontLoadingError, Style};
+use crate::font_data::FontData;
+#[cfg(feature = "harfbuzz")]
+use crate::hb_layout::HbFace;
+#[cfg(feature = "font-kit")]
+use crate::generic::{GenericFamily, GenericFamilyResolver};
+use crate::script::{Script, SCRIPT_UNKNOWN};
 use crate::Font;
 
+// Key for fallback lookups: the script and the locale applying to it.
+type FallbackKey = (Script, Option<String>);
+
 /// A collection of fonts
 pub struct FontCollection {
     pub(crate) families: Vec<FontFamily>,
+    fallback: Option<Box<dyn FallbackProvider>>,
+    variant: FamilyVariant,
+    // Families added on demand from the fallback provider. Their indices
+    // follow on from `families`.
+    fallback_families: RefCell<Vec<FontFamily>>,
+    // Fallback families already chosen for a script and locale, in the order
+    // they were added, so that later lookups don't go to the provider.
+    fallback_cache: RefCell<HashMap<FallbackKey, Vec<usize>>>,
+    // Characters the provider had nothing for.
+    fallback_misses: RefCell<HashSet<char>>,
 }
 
 pub struct FontFamily {
-    // TODO: multiple weights etc
     pub(crate) fonts: Vec<FontRef>,
+    // Index of the member closest to normal style and weight. This is the one
+    // used for coverage and, until style matching is done, for layout.
+    default_ix: usize,
+    // BCP-47 tags the family is intended for; empty if unspecified.
+    lang: Vec<String>,
+    variant: FamilyVariant,
+}
+
+/// The variant of a family, as in Android's fonts.xml.
+///
+/// Compact families are designed for UI with tight line heights; elegant
+/// families preserve the traditional proportions of the script.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FamilyVariant {
+    #[default]
+    Default,
+    Compact,
+    Elegant,
 }
 
 // Design question: deref to Font?
 #[derive(Clone)]
 pub struct FontRef {
     pub font: Arc<Font>,
+    // Shared between clones, and distinct for each `FontRef::new`.
+    id: FontId,
+    // Coverage read from the cmap, shared between clones. `None` if the loader
+    // couldn't give us the table, in which case we ask the font directly.
+    coverage: Option<Arc<SparseBitSet>>,
+    // The font file and face index, if we have them, so the shaper can share
+    // the bytes rather than asking the loader for a copy.
+    pub(crate) data: Option<(FontData, u32)>,
+    // The HarfBuzz face, created the first time the font is shaped, and
+    // shared between clones.
+    #[cfg(feature = "harfbuzz")]
+    pub(crate) hb_face: Arc<OnceCell<HbFace>>,
+    // A copy of the file from the loader, for rustybuzz when we don't have
+    // `data`. Made the first time the font is shaped, and shared between
+    // clones.
+    #[cfg(feature = "rustybuzz")]
+    pub(crate) copied_data: Arc<OnceCell<Option<(FontData, u32)>>>,
 }
 
 impl fmt::Debug for FontRef {
@@ -31,26 +95,123 @@ impl fmt::Debug for FontRef {
 pub struct Itemizer<'a> {
     text: &'a str,
     collection: &'a FontCollection,
+    script: Script,
+    locales: &'a [String],
     ix: usize,
 }
 
 impl FontRef {
+    // The font-kit loaders are not thread-safe; FontRef is shared within a thread.
+    #[allow(clippy::arc_with_non_send_sync)]
     pub fn new(font: Font) -> FontRef {
+        let coverage = font_coverage(&font).map(Arc::new);
+        let data = shared_data(&font);
         FontRef {
             font: Arc::new(font),
+            id: FontId::next(),
+            coverage,
+            data,
+            #[cfg(feature = "harfbuzz")]
+            hb_face: Arc::new(OnceCell::new()),
+            #[cfg(feature = "rustybuzz")]
+            copied_data: Arc::new(OnceCell::new()),
+        }
+    }
+
+    /// Create a font ref that shares `data` with the shaper.
+    ///
+    /// `data` must be the file the font was loaded from, and `index` its face
+    /// index within that file. This is checked by comparing the font's head
+    /// table with the one at `index`, and it's an error if they differ.
+    pub fn with_data(font: Font, data: FontData, index: u32) -> Result<FontRef, FontLoadingError> {
+        if !data_matches(&font, &data, index) {
+            return Err(FontLoadingError::NoSuchFontInCollection);
+        }
+        Ok(FontRef {
+            data: Some((data, index)),
+            ..FontRef::new(font)
+        })
+    }
+
+    /// The font file and face index, if known.
+    pub fn data(&self) -> Option<(&FontData, u32)> {
+        self.data.as_ref().map(|(data, index)| (data, *index))
+    }
+
+    /// Determine whether the font has a glyph for the codepoint.
+    pub fn supports_codepoint(&self, c: char) -> bool {
+        if let Some(coverage) = &self.coverage {
+            coverage.contains(c.into())
+        } else {
+            // TODO(font-kit): We're getting Some(0) for unsupported glyphs on CoreText
+            // and DirectWrite
+            self.font.glyph_for_char(c).unwrap_or(0) != 0
         }
     }
 }
 
+impl Default for FontFamily {
+    fn default() -> FontFamily {
+        FontFamily::new()
+    }
+}
+
 impl FontFamily {
     pub fn new() -> FontFamily {
-        FontFamily { fonts: Vec::new() }
+        FontFamily {
+            fonts: Vec::new(),
+            default_ix: 0,
+            lang: Vec::new(),
+            variant: FamilyVariant::Default,
+        }
     }
 
     pub fn add_font(&mut self, font: FontRef) {
+        if let Some(default) = self.fonts.get(self.default_ix) {
+            if distance_from_normal(&font) < distance_from_normal(default) {
+                self.default_ix = self.fonts.len();
+            }
+        }
         self.fonts.push(font);
     }
 
+    pub fn fonts(&self) -> &[FontRef] {
+        &self.fonts
+    }
+
+    /// The member closest to normal style and weight.
+    pub fn default_font(&self) -> Option<&FontRef> {
+        self.fonts.get(self.default_ix)
+    }
+
+    /// Make the member at `ix` the default.
+    ///
+    /// This is for when the style is known better than the font's own
+    /// metadata says, as with the weights and styles in Android's fonts.xml.
+    pub fn set_default_font(&mut self, ix: usize) {
+        assert!(ix < self.fonts.len(), "font index out of range");
+        self.default_ix = ix;
+    }
+
+    /// Sort the members by style, then weight, then stretch.
+    pub fn sort_fonts(&mut self) {
+        let default = self.default_font().map(FontId::from_font);
+        self.fonts.sort_by(|a, b| {
+            let (a, b) = (a.font.properties(), b.font.properties());
+            style_order(a.style)
+                .cmp(&style_order(b.style))
+                .then(a.weight.0.total_cmp(&b.weight.0))
+                .then(a.stretch.0.total_cmp(&b.stretch.0))
+        });
+        if let Some(default) = default {
+            self.default_ix = self
+                .fonts
+                .iter()
+                .position(|font| FontId::from_font(font) == default)
+                .unwrap_or(0);
+        }
+    }
+
     /// Create a collection consisting of a single font
     pub fn new_from_font(font: Font) -> FontFamily {
         let mut result = FontFamily::new();
@@ -58,82 +219,349 @@ impl FontFamily {
         result
     }
 
+    /// Load a family by name from a font source.
+    #[cfg(feature = "font-kit")]
+    pub fn from_source(source: &dyn Source, name: &str) -> Option<FontFamily> {
+        let handle = source.select_family_by_name(name).ok()?;
+        let mut family = FontFamily::new();
+        for font in handle.fonts().iter().filter_map(|handle| handle.load().ok()) {
+            family.add_font(FontRef::new(font));
+        }
+        family.sort_fonts();
+        if family.fonts.is_empty() {
+            None
+        } else {
+            Some(family)
+        }
+    }
+
+    /// Set the languages the family is intended for, as BCP-47 tags.
+    ///
+    /// When several such families cover a character, the one matching the
+    /// style's locale is preferred.
+    pub fn set_lang(&mut self, lang: Vec<String>) {
+        self.lang = lang;
+    }
+
+    pub fn lang(&self) -> &[String] {
+        &self.lang
+    }
+
+    pub fn set_variant(&mut self, variant: FamilyVariant) {
+        self.variant = variant;
+    }
+
+    pub fn variant(&self) -> FamilyVariant {
+        self.variant
+    }
+
+    fn has_variant(&self, variant: FamilyVariant) -> bool {
+        variant == FamilyVariant::Default
+            || self.variant == FamilyVariant::Default
+            || self.variant == variant
+    }
+
+    fn matches_locale(&self, locale: &str) -> bool {
+        self.lang.iter().any(|lang| lang_matches(lang, locale))
+    }
+
     pub fn supports_codepoint(&self, c: char) -> bool {
-        if let Some(font) = self.fonts.first() {
-            let glyph_id = font.font.glyph_for_char(c);
-            // TODO(font-kit): We're getting Some(0) for unsupported glyphs on CoreText
-            // and DirectWrite
-            glyph_id.unwrap_or(0) != 0
+        if let Some(font) = self.default_font() {
+            font.supports_codepoint(c)
         } else {
             false
         }
     }
 }
 
+impl Default for FontCollection {
+    fn default() -> FontCollection {
+        FontCollection::new()
+    }
+}
+
 impl FontCollection {
     pub fn new() -> FontCollection {
         FontCollection {
             families: Vec::new(),
+            fallback: None,
+            variant: FamilyVariant::Default,
+            fallback_families: RefCell::new(Vec::new()),
+            fallback_cache: RefCell::new(HashMap::new()),
+            fallback_misses: RefCell::new(HashSet::new()),
         }
     }
 
+    /// Create a collection from a CSS-style family list.
+    ///
+    /// Names such as "sans-serif" or "emoji" are resolved with the generic
+    /// family resolver. A generic expands to its general mapping followed by
+    /// its script- and locale-specific mappings, the latter tagged with their
+    /// locale so that itemization can choose between them. Families that
+    /// can't be found in the source are skipped.
+    #[cfg(feature = "font-kit")]
+    pub fn from_family_list(
+        names: &[&str],
+        resolver: &GenericFamilyResolver,
+        source: &dyn Source,
+    ) -> FontCollection {
+        let mut collection = FontCollection::new();
+        let mut added: Vec<(String, Option<String>)> = Vec::new();
+        let mut add = |collection: &mut FontCollection, name: &str, locale: Option<&str>| {
+            let key = (name.to_string(), locale.map(String::from));
+            if added.contains(&key) {
+                return;
+            }
+            match FontFamily::from_source(source, name) {
+                Some(mut family) => {
+                    if let Some(locale) = locale {
+                        family.set_lang(vec![locale.to_string()]);
+                    }
+                    collection.add_family(family);
+                    added.push(key);
+                }
+                None => warn!("font family {:?} not found", name),
+            }
+        };
+        for name in names {
+            match GenericFamily::from_name(name) {
+                Some(generic) => {
+                    for (locale, families) in resolver.expand(generic) {
+                        for family in families {
+                            add(&mut collection, family, locale);
+                        }
+                    }
+                }
+                None => add(&mut collection, name, None),
+            }
+        }
+        collection
+    }
+
     pub fn add_family(&mut self, family: FontFamily) {
         self.families.push(family);
     }
 
+    /// Set the provider consulted when no family covers a character.
+    ///
+    /// Families obtained from the provider are cached in the collection, so
+    /// this is best set up front.
+    pub fn set_fallback_provider(&mut self, provider: impl FallbackProvider + 'static) {
+        self.fallback = Some(Box::new(provider));
+    }
+
+    /// Set the variant to prefer when choosing between families.
+    ///
+    /// Families of another variant are then only used for characters that
+    /// no family of this variant (or of the default one) covers. This is
+    /// like Android's elegant text height setting.
+    pub fn set_variant(&mut self, variant: FamilyVariant) {
+        self.variant = variant;
+    }
+
+    pub fn variant(&self) -> FamilyVariant {
+        self.variant
+    }
+
     pub fn itemize<'a>(&'a self, text: &'a str) -> Itemizer<'a> {
+        self.itemize_script(text, SCRIPT_UNKNOWN, &[])
+    }
+
+    /// Itemize a run of text in a single script.
+    ///
+    /// The script and locale list are used to select fallback fonts.
+    pub fn itemize_script<'a>(
+        &'a self,
+        text: &'a str,
+        script: Script,
+        locales: &'a [String],
+    ) -> Itemizer<'a> {
         Itemizer {
             text,
             collection: self,
+            script,
+            locales,
             ix: 0,
         }
     }
 
-    // TODO: other style params, including locale list
-    fn choose_font(&self, c: char) -> usize {
-        self.families
+    fn choose_font(&self, c: char, script: Script, locales: &[String]) -> usize {
+        let covering = |any_variant: bool| {
+            self.families.iter().enumerate().filter(move |(_, family)| {
+                (any_variant || family.has_variant(self.variant)) && family.supports_codepoint(c)
+            })
+        };
+        // Other variants are only used if no family of ours covers c.
+        let mut covering = match covering(false).next() {
+            Some(_) => covering(false),
+            None => covering(true),
+        };
+        if let Some((ix, first)) = covering.next() {
+            // Families without language metadata are chosen in order. Among
+            // families with it (the fallback families of a system config),
+            // prefer one matching the locale, as for Han unification.
+            if first.lang.is_empty() {
+                return ix;
+            }
+            if let Some(locale) = locale_for_script(locales, script) {
+                if !first.matches_locale(locale) {
+                    let better = covering
+                        .filter(|(_, family)| !family.lang.is_empty())
+                        .find(|(_, family)| family.matches_locale(locale));
+                    if let Some((better_ix, _)) = better {
+                        return better_ix;
+                    }
+                }
+            }
+            return ix;
+        }
+        self.choose_fallback(c, script, locales).unwrap_or(0)
+    }
+
+    fn choose_fallback(&self, c: char, script: Script, locales: &[String]) -> Option<usize> {
+        let provider = self.fallback.as_ref()?;
+        let n_families = self.families.len();
+        let key = (script, locale_for_script(locales, script).map(String::from));
+        let mut cache = self.fallback_cache.borrow_mut();
+        let cached = cache.entry(key).or_default();
+        let mut fallback_families = self.fallback_families.borrow_mut();
+        for &ix in cached.iter() {
+            if fallback_families[ix].supports_codepoint(c) {
+                return Some(n_families + ix);
+            }
+        }
+        if self.fallback_misses.borrow().contains(&c) {
+            return None;
+        }
+        let font = match provider.fallbacks(c, script, locales).next() {
+            Some(font) => font,
+            None => {
+                self.fallback_misses.borrow_mut().insert(c);
+                return None;
+            }
+        };
+        let font_id = FontId::from_font(&font);
+        let existing = fallback_families
             .iter()
-            .position(|family| family.supports_codepoint(c))
-            .unwrap_or(0)
+            .position(|family| family.default_font().map(FontId::from_font) == Some(font_id));
+        let ix = existing.unwrap_or_else(|| {
+            debug!("adding fallback family {:?} for {:?}", font, c);
+            let mut family = FontFamily::new();
+            family.add_font(font);
+            fallback_families.push(family);
+            fallback_families.len() - 1
+        });
+        cached.push(ix);
+        Some(n_families + ix)
+    }
+
+    /// The default font of the family at `ix`, including fallback families.
+    fn family_font(&self, ix: usize) -> Option<FontRef> {
+        if ix < self.families.len() {
+            self.families[ix].default_font().cloned()
+        } else {
+            let fallback_families = self.fallback_families.borrow();
+            fallback_families.get(ix - self.families.len())?.default_font().cloned()
+        }
+    }
+}
+
+fn style_order(style: Style) -> u8 {
+    match style {
+        Style::Normal => 0,
+        Style::Italic => 1,
+        Style::Oblique => 2,
     }
 }
 
-// This is the PostScript name of the font. Eventually this should be a unique ID.
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
-pub(crate) struct FontId {
-    postscript_name: String,
+// A rough distance from normal style, weight and stretch, for choosing the
+// default member of a family.
+fn distance_from_normal(font: &FontRef) -> u32 {
+    let properties = font.font.properties();
+    let style = if properties.style == Style::Normal { 0 } else { 10_000 };
+    let weight = (properties.weight.0 - 400.0).abs() as u32;
+    let stretch = ((properties.stretch.0 - 1.0).abs() * 1000.0) as u32;
+    style + weight + stretch
 }
 
+/// Whether a family's language tag applies to a locale.
+///
+/// The primary language must match. If both specify a script (inferring it
+/// from the region for Chinese), the scripts must match too. Families tagged
+/// "und" are script-specific rather than language-specific, so don't match.
+pub(crate) fn lang_matches(family_lang: &str, locale: &str) -> bool {
+    let (family_lang, family_script) = lang_and_script(family_lang);
+    let (lang, script) = lang_and_script(locale);
+    if family_lang == "und" || !family_lang.eq_ignore_ascii_case(lang) {
+        return false;
+    }
+    match (family_script, script) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        _ => true,
+    }
+}
+
+fn lang_and_script(tag: &str) -> (&str, Option<&str>) {
+    let mut subtags = tag.split(['-', '_']);
+    let lang = subtags.next().unwrap_or_default();
+    let mut script = None;
+    let mut region = None;
+    for subtag in subtags {
+        match subtag.len() {
+            4 if script.is_none() => script = Some(subtag),
+            2 | 3 if region.is_none() => region = Some(subtag),
+            _ => (),
+        }
+    }
+    if script.is_none() && lang.eq_ignore_ascii_case("zh") {
+        script = match region.map(|r| r.to_ascii_uppercase()).as_deref() {
+            Some("TW") | Some("HK") | Some("MO") => Some("Hant"),
+            _ => Some("Hans"),
+        };
+    }
+    (lang, script)
+}
+
+/// Identifies a `FontRef` and its clones.
+///
+/// Names aren't unique, and not all fonts have a PostScript name, so each
+/// `FontRef::new` gets a fresh ID instead. The same face loaded twice gets two
+/// IDs; fallback providers return clones to avoid that.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct FontId(u64);
+
 impl FontId {
+    fn next() -> FontId {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        FontId(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+
     pub(crate) fn from_font(font: &FontRef) -> FontId {
-        FontId { postscript_name: font.font.postscript_name().unwrap_or_default() }
+        font.id
     }
 }
 
 impl<'a> Iterator for Itemizer<'a> {
-    type Item = (Range<usize>, &'a FontRef);
+    type Item = (Range<usize>, FontRef);
 
-    fn next(&mut self) -> Option<(Range<usize>, &'a FontRef)> {
+    fn next(&mut self) -> Option<(Range<usize>, FontRef)> {
         let start = self.ix;
         let mut chars_iter = self.text[start..].chars();
         if let Some(c) = chars_iter.next() {
+            let collection = self.collection;
             let mut end = start + c.len_utf8();
-            let font_ix = self.collection.choose_font(c);
+            let font_ix = collection.choose_font(c, self.script, self.locales);
             debug!("{}: {}", c, font_ix);
-            while let Some(c) = chars_iter.next() {
-                if font_ix != self.collection.choose_font(c) {
+            for c in chars_iter {
+                if font_ix != collection.choose_font(c, self.script, self.locales) {
                     break;
                 }
                 end += c.len_utf8();
             }
             self.ix = end;
 
-            if &self.collection.families.len() >= &1 {
-                Some((start..end, &self.collection.families[font_ix].fonts[0]))
-            }
-            else {
-                None
-            }
+            let font = collection.family_font(font_ix)?;
+            Some((start..end, font))
         } else {
             None
         }
@@ -0,0 +1,408 @@
+//! Unicode coverage of fonts, derived from the cmap table.
+//!
+//! This follows the approach of Minikin's `SparseBitSet` and `CmapCoverage`: the
+//! cmap is parsed once when the font is added, and the resulting set answers
+//! coverage queries during itemization without going through the font loader.
+
+use std::convert::TryInto;
+
+use crate::Font;
+
+const CMAP_TAG: u32 = 0x636d_6170; // 'cmap'
+
+// Each page covers 256 codepoints, stored as 4 u64 elements.
+const LOG_VALUES_PER_PAGE: u32 = 8;
+const ELEMENT_SHIFT: u32 = 6;
+const ELEMENT_MASK: u32 = (1 << ELEMENT_SHIFT) - 1;
+const ELEMENTS_PER_PAGE: usize = 1 << (LOG_VALUES_PER_PAGE - ELEMENT_SHIFT);
+const NO_ZERO_PAGE: u32 = 0xffff_ffff;
+
+/// A compact set of codepoints.
+///
+/// The bitmap is split into pages of 256 codepoints. Pages that are entirely
+/// empty share a single zero page, and pages that are entirely full share a
+/// single full page, so fonts with large contiguous blocks (CJK) stay small.
+#[derive(Debug, Default)]
+pub(crate) struct SparseBitSet {
+    max_val: u32,
+    indices: Vec<u32>,
+    bitmaps: Vec<u64>,
+}
+
+impl SparseBitSet {
+    /// Build the set from a sorted list of non-overlapping half-open ranges.
+    pub(crate) fn from_ranges(ranges: &[(u32, u32)]) -> SparseBitSet {
+        let max_val = match ranges.last() {
+            Some(&(_, end)) => end,
+            None => return SparseBitSet::default(),
+        };
+        let n_pages = ((max_val + (1 << LOG_VALUES_PER_PAGE) - 1) >> LOG_VALUES_PER_PAGE) as usize;
+        let mut indices = vec![NO_ZERO_PAGE; n_pages];
+        let mut bitmaps = Vec::new();
+        let mut zero_page = NO_ZERO_PAGE;
+        let mut full_page = NO_ZERO_PAGE;
+        let mut range_ix = 0;
+        for (page, index) in indices.iter_mut().enumerate() {
+            let page_start = (page as u32) << LOG_VALUES_PER_PAGE;
+            let page_end = page_start + (1 << LOG_VALUES_PER_PAGE);
+            while range_ix < ranges.len() && ranges[range_ix].1 <= page_start {
+                range_ix += 1;
+            }
+            let mut elements = [0u64; ELEMENTS_PER_PAGE];
+            let mut ix = range_ix;
+            while ix < ranges.len() && ranges[ix].0 < page_end {
+                let start = ranges[ix].0.max(page_start) - page_start;
+                let end = ranges[ix].1.min(page_end) - page_start;
+                for val in start..end {
+                    elements[(val >> ELEMENT_SHIFT) as usize] |= 1 << (val & ELEMENT_MASK);
+                }
+                ix += 1;
+            }
+            let shared = if elements.iter().all(|&e| e == 0) {
+                Some(&mut zero_page)
+            } else if elements.iter().all(|&e| e == !0) {
+                Some(&mut full_page)
+            } else {
+                None
+            };
+            *index = match shared {
+                Some(shared) if *shared != NO_ZERO_PAGE => *shared,
+                Some(shared) => {
+                    *shared = (bitmaps.len() / ELEMENTS_PER_PAGE) as u32;
+                    bitmaps.extend_from_slice(&elements);
+                    *shared
+                }
+                None => {
+                    let new_page = (bitmaps.len() / ELEMENTS_PER_PAGE) as u32;
+                    bitmaps.extend_from_slice(&elements);
+                    new_page
+                }
+            };
+        }
+        SparseBitSet {
+            max_val,
+            indices,
+            bitmaps,
+        }
+    }
+
+    pub(crate) fn contains(&self, c: u32) -> bool {
+        if c >= self.max_val {
+            return false;
+        }
+        let page = self.indices[(c >> LOG_VALUES_PER_PAGE) as usize] as usize;
+        let val = c & ((1 << LOG_VALUES_PER_PAGE) - 1);
+        let element = self.bitmaps[page * ELEMENTS_PER_PAGE + (val >> ELEMENT_SHIFT) as usize];
+        element & (1 << (val & ELEMENT_MASK)) != 0
+    }
+}
+
+/// Compute the coverage of a font by reading its cmap table.
+///
+/// Returns `None` if the loader can't provide the table, or if it doesn't
+/// contain a Unicode subtable we understand.
+pub(crate) fn font_coverage(font: &Font) -> Option<SparseBitSet> {
+    let cmap = font.load_font_table(CMAP_TAG)?;
+    let ranges = cmap_ranges(&cmap)?;
+    Some(SparseBitSet::from_ranges(&ranges))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes(bytes.try_into().unwrap()))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes(bytes.try_into().unwrap()))
+}
+
+/// Parse the best Unicode subtable of a cmap into sorted, merged ranges.
+///
+/// Codepoints mapping to glyph 0 (.notdef) are excluded, which sidesteps
+/// loaders that report `Some(0)` for unsupported characters.
+fn cmap_ranges(cmap: &[u8]) -> Option<Vec<(u32, u32)>> {
+    let n_tables = read_u16(cmap, 2)? as usize;
+    // Preference order follows Minikin: full-repertoire subtables first.
+    let mut best: Option<(u32, usize)> = None;
+    for i in 0..n_tables {
+        let record = 4 + i * 8;
+        let platform_id = read_u16(cmap, record)?;
+        let encoding_id = read_u16(cmap, record + 2)?;
+        let offset = read_u32(cmap, record + 4)? as usize;
+        let format = read_u16(cmap, offset)?;
+        let priority = match (platform_id, encoding_id, format) {
+            (3, 10, 12) => 0,
+            (0, 4, 12) | (0, 6, 12) => 1,
+            (3, 1, 4) => 2,
+            (0, 3, 4) => 3,
+            (0, 0..=2, 4) => 4,
+            _ => continue,
+        };
+        if best.map(|(p, _)| priority < p).unwrap_or(true) {
+            best = Some((priority, offset));
+        }
+    }
+    let (_, offset) = best?;
+    let subtable = &cmap[offset..];
+    let mut ranges = match read_u16(subtable, 0)? {
+        4 => format4_ranges(subtable)?,
+        12 => format12_ranges(subtable)?,
+        _ => return None,
+    };
+    ranges.sort_unstable();
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    Some(merged)
+}
+
+fn format4_ranges(data: &[u8]) -> Option<Vec<(u32, u32)>> {
+    let seg_count = (read_u16(data, 6)? / 2) as usize;
+    let end_codes = 14;
+    let start_codes = end_codes + seg_count * 2 + 2;
+    let id_deltas = start_codes + seg_count * 2;
+    let id_range_offsets = id_deltas + seg_count * 2;
+    let mut ranges = Vec::new();
+    for i in 0..seg_count {
+        let end = read_u16(data, end_codes + i * 2)? as u32;
+        let start = read_u16(data, start_codes + i * 2)? as u32;
+        let delta = read_u16(data, id_deltas + i * 2)? as u32;
+        let range_offset = read_u16(data, id_range_offsets + i * 2)? as usize;
+        if end < start || (start == 0xffff && end == 0xffff) {
+            continue;
+        }
+        if range_offset == 0 {
+            // Every codepoint maps to (c + delta) mod 65536; at most one of
+            // them hits glyph 0.
+            let zero = (0x1_0000 - delta) & 0xffff;
+            if zero >= start && zero <= end {
+                push_range(&mut ranges, start, zero);
+                push_range(&mut ranges, zero + 1, end + 1);
+            } else {
+                push_range(&mut ranges, start, end + 1);
+            }
+        } else {
+            let mut run_start = None;
+            for c in start..=end {
+                let glyph_offset =
+                    id_range_offsets + i * 2 + range_offset + ((c - start) as usize) * 2;
+                // A nonzero glyph still gets the delta, which may bring it
+                // back to 0.
+                let glyph = read_u16(data, glyph_offset)? as u32;
+                let mapped = glyph != 0 && (glyph + delta) & 0xffff != 0;
+                match (mapped, run_start) {
+                    (true, None) => run_start = Some(c),
+                    (false, Some(s)) => {
+                        push_range(&mut ranges, s, c);
+                        run_start = None;
+                    }
+                    _ => (),
+                }
+            }
+            if let Some(s) = run_start {
+                push_range(&mut ranges, s, end + 1);
+            }
+        }
+    }
+    Some(ranges)
+}
+
+fn format12_ranges(data: &[u8]) -> Option<Vec<(u32, u32)>> {
+    let n_groups = read_u32(data, 12)? as usize;
+    let mut ranges = Vec::with_capacity(n_groups);
+    for i in 0..n_groups {
+        let group = 16 + i * 12;
+        let start = read_u32(data, group)?;
+        let end = read_u32(data, group + 4)?;
+        let start_glyph = read_u32(data, group + 8)?;
+        if end < start || end > 0x10_ffff {
+            continue;
+        }
+        let start = if start_glyph == 0 { start + 1 } else { start };
+        push_range(&mut ranges, start, end + 1);
+    }
+    Some(ranges)
+}
+
+fn push_range(ranges: &mut Vec<(u32, u32)>, start: u32, end: u32) {
+    if start < end {
+        ranges.push((start, end));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::test_util::{font_bytes, ESTEDAD, MADA};
+
+    fn u16s(values: &[u16]) -> Vec<u8> {
+        values.iter().flat_map(|v| v.to_be_bytes()).collect()
+    }
+
+    fn u32s(values: &[u32]) -> Vec<u8> {
+        values.iter().flat_map(|v| v.to_be_bytes()).collect()
+    }
+
+    /// A cmap table with the given (platform, encoding, subtable) records.
+    fn cmap(subtables: &[(u16, u16, Vec<u8>)]) -> Vec<u8> {
+        let mut out = u16s(&[0, subtables.len() as u16]);
+        let mut offset = 4 + 8 * subtables.len();
+        for (platform, encoding, subtable) in subtables {
+            out.extend(u16s(&[*platform, *encoding]));
+            out.extend(u32s(&[offset as u32]));
+            offset += subtable.len();
+        }
+        for (_, _, subtable) in subtables {
+            out.extend(subtable);
+        }
+        out
+    }
+
+    /// A format 4 subtable from (start, end, id_delta, glyph array index)
+    /// segments; segments without an index use id_delta.
+    fn format4(segments: &[(u16, u16, u16, Option<usize>)], glyph_ids: &[u16]) -> Vec<u8> {
+        let seg_count = segments.len();
+        let range_offset = |(i, seg): (usize, &(u16, u16, u16, Option<usize>))| match seg.3 {
+            // Relative to the segment's own idRangeOffset entry.
+            Some(index) => ((seg_count - i + index) * 2) as u16,
+            None => 0,
+        };
+        let mut out = u16s(&[4, 0, 0, seg_count as u16 * 2, 0, 0, 0]);
+        out.extend(u16s(&segments.iter().map(|s| s.1).collect::<Vec<_>>()));
+        out.extend(u16s(&[0]));
+        out.extend(u16s(&segments.iter().map(|s| s.0).collect::<Vec<_>>()));
+        out.extend(u16s(&segments.iter().map(|s| s.2).collect::<Vec<_>>()));
+        out.extend(u16s(
+            &segments
+                .iter()
+                .enumerate()
+                .map(range_offset)
+                .collect::<Vec<_>>(),
+        ));
+        out.extend(u16s(glyph_ids));
+        let len = out.len() as u16;
+        out[2..4].copy_from_slice(&len.to_be_bytes());
+        out
+    }
+
+    /// A format 12 subtable from (start, end, start glyph) groups.
+    fn format12(groups: &[(u32, u32, u32)]) -> Vec<u8> {
+        let mut out = u16s(&[12, 0]);
+        out.extend(u32s(&[
+            16 + 12 * groups.len() as u32,
+            0,
+            groups.len() as u32,
+        ]));
+        for &(start, end, glyph) in groups {
+            out.extend(u32s(&[start, end, glyph]));
+        }
+        out
+    }
+
+    #[test]
+    fn format4_excludes_glyph_zero() {
+        let subtable = format4(
+            &[
+                // 0x21 + 0xffdf wraps around to glyph 0.
+                (0x20, 0x22, 0xffdf, None),
+                (0x41, 0x43, 0, Some(0)),
+                (0xffff, 0xffff, 1, None),
+            ],
+            &[5, 0, 7],
+        );
+        let ranges = cmap_ranges(&cmap(&[(3, 1, subtable)])).unwrap();
+        assert_eq!(
+            ranges,
+            vec![(0x20, 0x21), (0x22, 0x23), (0x41, 0x42), (0x43, 0x44)]
+        );
+    }
+
+    #[test]
+    fn format4_glyph_array_delta() {
+        // idDelta also applies to nonzero entries of the glyph array, and
+        // can bring them to 0.
+        let subtable = format4(
+            &[(0x61, 0x62, 0xfffe, Some(0)), (0xffff, 0xffff, 1, None)],
+            &[2, 3],
+        );
+        let ranges = cmap_ranges(&cmap(&[(3, 1, subtable)])).unwrap();
+        assert_eq!(ranges, vec![(0x62, 0x63)]);
+    }
+
+    #[test]
+    fn format12_excludes_glyph_zero() {
+        let subtable = format12(&[(0, 2, 0), (0x1f600, 0x1f602, 100)]);
+        let ranges = cmap_ranges(&cmap(&[(3, 10, subtable)])).unwrap();
+        assert_eq!(ranges, vec![(1, 3), (0x1f600, 0x1f603)]);
+    }
+
+    #[test]
+    fn prefers_full_unicode_subtable() {
+        let bmp = format4(&[(0x41, 0x41, 1, None), (0xffff, 0xffff, 1, None)], &[]);
+        let full = format12(&[(0x10000, 0x10000, 1)]);
+        let ranges = cmap_ranges(&cmap(&[(3, 1, bmp), (3, 10, full)])).unwrap();
+        assert_eq!(ranges, vec![(0x10000, 0x10001)]);
+    }
+
+    #[test]
+    fn pages_are_shared() {
+        // Pages 0, 2 and 4 are empty; 1, 3 and 5 are full; 6 is partial.
+        let set = SparseBitSet::from_ranges(&[
+            (0x100, 0x200),
+            (0x300, 0x400),
+            (0x500, 0x600),
+            (0x610, 0x611),
+        ]);
+        assert_eq!(set.indices.len(), 7);
+        assert_eq!(set.indices[0], set.indices[2]);
+        assert_eq!(set.indices[0], set.indices[4]);
+        assert_eq!(set.indices[1], set.indices[3]);
+        assert_eq!(set.indices[1], set.indices[5]);
+        assert_ne!(set.indices[0], set.indices[1]);
+        assert_eq!(set.bitmaps.len(), 3 * ELEMENTS_PER_PAGE);
+        assert!(!set.contains(0xff));
+        assert!(set.contains(0x100));
+        assert!(set.contains(0x1ff));
+        assert!(!set.contains(0x200));
+        assert!(!set.contains(0x60f));
+        assert!(set.contains(0x610));
+        assert!(!set.contains(0x611));
+        assert!(!set.contains(0x10ffff));
+    }
+
+    #[test]
+    fn empty_set() {
+        let set = SparseBitSet::from_ranges(&[]);
+        assert!(!set.contains(0));
+        assert!(!set.contains(0x41));
+    }
+
+    fn check_against_cmap_lookup(data: Vec<u8>) {
+        let font = Font::from_bytes(Arc::new(data), 0).unwrap();
+        let coverage = font_coverage(&font).unwrap();
+        for c in (0..=0x10ffff).filter_map(std::char::from_u32) {
+            let has_glyph = font.glyph_for_char(c).is_some_and(|glyph| glyph != 0);
+            assert_eq!(coverage.contains(c.into()), has_glyph, "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn matches_glyph_for_char() {
+        check_against_cmap_lookup(font_bytes(MADA));
+        check_against_cmap_lookup(font_bytes(ESTEDAD));
+        // A system font with more subtables and blocks, if present.
+        let dejavu = Path::new("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf");
+        if let Ok(data) = fs::read(dejavu) {
+            check_against_cmap_lookup(data);
+        }
+    }
+}
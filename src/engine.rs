@@ -0,0 +1,405 @@
+//! The layout engine, which owns a cache of shaped runs shared by sessions.
+//!
+//! Like Minikin's `LayoutCache`, the cache is keyed by the text and
+//! everything that affects its shaping, and there is a global engine that
+//! `LayoutSession::create` uses. Fonts can't be shared between threads, so
+//! entries only hold the shaped glyphs, with the font's id in the key.
+
+use std::any::TypeId;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+use crate::collection::FontId;
+use crate::script::Script;
+use crate::shaper::{
+    context_after, context_before, DefaultShaper, Direction, Feature, ShapeParams, ShapedGlyph,
+    ShapedRun, Shaper,
+};
+
+/// The capacity of the global engine's cache, in bytes.
+const DEFAULT_CAPACITY: usize = 8 << 20;
+
+/// The cache is split into shards, each with its own lock, so that threads
+/// rarely wait for each other.
+const N_SHARDS: usize = 16;
+
+/// A factory for layouts, holding a cache of shaped runs.
+///
+/// The cache is bounded in memory, evicting the least recently used runs.
+/// An engine can be shared between threads.
+pub struct Engine {
+    cache: Arc<LayoutCache>,
+}
+
+/// Statistics of an engine's cache.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+    /// The approximate memory used by the entries.
+    pub bytes: usize,
+}
+
+/// A shaper that looks runs up in an engine's cache, and shapes them with
+/// another shaper, by default the default one, on a miss.
+pub struct CachedShaper<S = DefaultShaper> {
+    cache: Arc<LayoutCache>,
+    shaper: S,
+}
+
+struct LayoutCache {
+    shards: Vec<Mutex<Shard>>,
+    shard_capacity: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Default)]
+struct Shard {
+    entries: HashMap<Arc<CacheKey>, CacheEntry>,
+    // Keys by the time of their last use, oldest first.
+    lru: BTreeMap<u64, Arc<CacheKey>>,
+    time: u64,
+    bytes: usize,
+}
+
+struct CacheEntry {
+    run: ShapedRun,
+    last_used: u64,
+    bytes: usize,
+}
+
+/// Everything the shaping of a run depends on.
+#[derive(PartialEq, Eq, Hash)]
+struct CacheKey {
+    shaper: TypeId,
+    text: String,
+    pre_context: String,
+    post_context: String,
+    font: FontId,
+    size_bits: u32,
+    script: Script,
+    direction: Direction,
+    language: Option<String>,
+    features: Vec<Feature>,
+}
+
+impl Engine {
+    /// An engine whose cache holds up to about `capacity` bytes.
+    pub fn new(capacity: usize) -> Engine {
+        Engine {
+            cache: Arc::new(LayoutCache {
+                shards: (0..N_SHARDS).map(|_| Mutex::default()).collect(),
+                shard_capacity: capacity / N_SHARDS,
+                hits: AtomicU64::new(0),
+                misses: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// The engine used by `LayoutSession::create` and `create_rich`.
+    pub fn global() -> &'static Engine {
+        static GLOBAL: OnceLock<Engine> = OnceLock::new();
+        GLOBAL.get_or_init(|| Engine::new(DEFAULT_CAPACITY))
+    }
+
+    /// A shaper using this engine's cache, for
+    /// `LayoutSession::create_with_shaper`.
+    pub fn shaper(&self) -> CachedShaper {
+        self.cached(DefaultShaper::default())
+    }
+
+    /// A shaper using this engine's cache in front of `shaper`.
+    ///
+    /// Runs are cached by the type of the shaper, so all shapers of a type
+    /// must give the same results.
+    pub fn cached<S: Shaper + 'static>(&self, shaper: S) -> CachedShaper<S> {
+        CachedShaper {
+            cache: self.cache.clone(),
+            shaper,
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let mut stats = CacheStats {
+            hits: self.cache.hits.load(Ordering::Relaxed),
+            misses: self.cache.misses.load(Ordering::Relaxed),
+            ..CacheStats::default()
+        };
+        for shard in &self.cache.shards {
+            let shard = lock(shard);
+            stats.entries += shard.entries.len();
+            stats.bytes += shard.bytes;
+        }
+        stats
+    }
+
+    /// Empty the cache. This doesn't reset the hit and miss counts.
+    pub fn clear(&self) {
+        for shard in &self.cache.shards {
+            *lock(shard) = Shard::default();
+        }
+    }
+}
+
+impl<S: Shaper + 'static> Shaper for CachedShaper<S> {
+    fn shape(&self, text: &str, params: &ShapeParams) -> ShapedRun {
+        let key = CacheKey {
+            shaper: TypeId::of::<S>(),
+            text: text.to_string(),
+            pre_context: context_before(params.pre_context).to_string(),
+            post_context: context_after(params.post_context).to_string(),
+            font: FontId::from_font(params.font),
+            size_bits: params.size.to_bits(),
+            script: params.script,
+            direction: params.direction,
+            language: params.language.map(str::to_string),
+            features: params.features.to_vec(),
+        };
+        if let Some(run) = self.cache.get(&key) {
+            self.cache.hits.fetch_add(1, Ordering::Relaxed);
+            return run;
+        }
+        self.cache.misses.fetch_add(1, Ordering::Relaxed);
+        // Shape without holding the lock.
+        let run = self.shaper.shape(text, params);
+        self.cache.insert(key, run.clone());
+        run
+    }
+}
+
+impl LayoutCache {
+    fn shard(&self, key: &CacheKey) -> &Mutex<Shard> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        &self.shards[hasher.finish() as usize % N_SHARDS]
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<ShapedRun> {
+        let mut guard = lock(self.shard(key));
+        let shard = &mut *guard;
+        let entry = shard.entries.get_mut(key)?;
+        let key = shard.lru.remove(&entry.last_used)?;
+        shard.time += 1;
+        entry.last_used = shard.time;
+        shard.lru.insert(shard.time, key);
+        Some(entry.run.clone())
+    }
+
+    fn insert(&self, key: CacheKey, run: ShapedRun) {
+        let bytes = mem::size_of::<CacheKey>()
+            + mem::size_of::<CacheEntry>()
+            + key.text.len()
+            + key.pre_context.len()
+            + key.post_context.len()
+            + key.features.len() * mem::size_of::<Feature>()
+            + run.glyphs.len() * mem::size_of::<ShapedGlyph>();
+        if bytes > self.shard_capacity {
+            return;
+        }
+        let mut guard = lock(self.shard(&key));
+        let shard = &mut *guard;
+        shard.time += 1;
+        let key = Arc::new(key);
+        shard.lru.insert(shard.time, key.clone());
+        let entry = CacheEntry {
+            run,
+            last_used: shard.time,
+            bytes,
+        };
+        // Another thread may have shaped the same run meanwhile.
+        if let Some(old) = shard.entries.insert(key, entry) {
+            shard.lru.remove(&old.last_used);
+            shard.bytes -= old.bytes;
+        }
+        shard.bytes += bytes;
+        while shard.bytes > self.shard_capacity {
+            let (_, oldest) = match shard.lru.pop_first() {
+                Some(oldest) => oldest,
+                None => break,
+            };
+            if let Some(evicted) = shard.entries.remove(&oldest) {
+                shard.bytes -= evicted.bytes;
+            }
+        }
+    }
+}
+
+// Shards are only locked for bookkeeping, not shaping, so a poisoned lock is
+// still fine to use.
+fn lock(shard: &Mutex<Shard>) -> MutexGuard<'_, Shard> {
+    shard
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::script::SCRIPT_LATIN;
+    use crate::test_util::{
+        collection, font_bytes, font_ref, read_u16, read_u32, FixedShaper, ESTEDAD, MADA,
+    };
+    use crate::{Font, FontRef, LayoutSession, TextStyle};
+
+    fn latin(font: &FontRef) -> ShapeParams<'_> {
+        ShapeParams {
+            font,
+            size: 16.0,
+            script: SCRIPT_LATIN,
+            direction: Direction::LeftToRight,
+            language: None,
+            features: &[],
+            pre_context: "",
+            post_context: "",
+        }
+    }
+
+    fn key(text: &str, font: &FontRef) -> CacheKey {
+        CacheKey {
+            shaper: TypeId::of::<FixedShaper>(),
+            text: text.to_string(),
+            pre_context: String::new(),
+            post_context: String::new(),
+            font: FontId::from_font(font),
+            size_bits: 16f32.to_bits(),
+            script: SCRIPT_LATIN,
+            direction: Direction::LeftToRight,
+            language: None,
+            features: Vec::new(),
+        }
+    }
+
+    // Overwrite the (Windows platform) PostScript name of a font, which must
+    // not be longer than the old one.
+    fn set_postscript_name(data: &mut [u8], name: &str) {
+        let name_table = (0..read_u16(data, 4))
+            .map(|i| 12 + 16 * i)
+            .find(|&record| &data[record..record + 4] == b"name")
+            .map(|record| read_u32(data, record + 8))
+            .unwrap();
+        let strings = name_table + read_u16(data, name_table + 4);
+        for i in 0..read_u16(data, name_table + 2) {
+            let record = name_table + 6 + 12 * i;
+            if read_u16(data, record + 6) == 6 {
+                let utf16: Vec<u8> = name.encode_utf16().flat_map(u16::to_be_bytes).collect();
+                assert!(utf16.len() <= read_u16(data, record + 8));
+                let offset = strings + read_u16(data, record + 10);
+                data[offset..offset + utf16.len()].copy_from_slice(&utf16);
+                data[record + 8..record + 10].copy_from_slice(&(utf16.len() as u16).to_be_bytes());
+            }
+        }
+    }
+
+    #[test]
+    fn same_name_different_fonts() {
+        let mada = font_ref(MADA);
+        let mut estedad = font_bytes(ESTEDAD);
+        set_postscript_name(&mut estedad, "Mada-Medium");
+        let estedad = FontRef::new(Font::from_bytes(Arc::new(estedad), 0).unwrap());
+        assert_eq!(mada.font.postscript_name(), estedad.font.postscript_name());
+
+        let engine = Engine::new(1 << 20);
+        let shaper = engine.shaper();
+        let shape = |font: &FontRef| shaper.shape("abc", &latin(font));
+        let mada_run = shape(&mada);
+        let estedad_run = shape(&estedad);
+        assert_ne!(mada_run.advance, estedad_run.advance);
+        assert_eq!(shape(&mada.clone()).advance, mada_run.advance);
+        let stats = engine.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 2));
+    }
+
+    #[test]
+    fn wraps_other_shapers() {
+        let mada = font_ref(MADA);
+        let engine = Engine::new(1 << 20);
+        let fixed = engine.cached(FixedShaper);
+        let glyph_ids = |run: &ShapedRun| run.glyphs.iter().map(|g| g.glyph_id).collect::<Vec<_>>();
+        let run = fixed.shape("abc", &latin(&mada));
+        assert_eq!(glyph_ids(&run), vec![0x61, 0x62, 0x63]);
+        assert_eq!(
+            glyph_ids(&fixed.shape("abc", &latin(&mada))),
+            glyph_ids(&run)
+        );
+        // The same run from another shaper isn't a hit.
+        let default_run = engine.shaper().shape("abc", &latin(&mada));
+        assert_ne!(glyph_ids(&default_run), glyph_ids(&run));
+        let stats = engine.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 2, 2));
+    }
+
+    #[test]
+    fn eviction_keeps_shards_within_capacity() {
+        let mada = font_ref(MADA);
+        let engine = Engine::new(N_SHARDS * 2000);
+        let shaper = engine.cached(FixedShaper);
+        for i in 0..500 {
+            shaper.shape(&format!("word {}", i), &latin(&mada));
+            for shard in &engine.cache.shards {
+                let shard = lock(shard);
+                assert!(shard.bytes <= engine.cache.shard_capacity);
+                let bytes: usize = shard.entries.values().map(|entry| entry.bytes).sum();
+                assert_eq!(shard.bytes, bytes);
+                assert_eq!(shard.lru.len(), shard.entries.len());
+            }
+        }
+        let stats = engine.stats();
+        assert!(stats.entries > 0 && stats.entries < 500);
+        assert_eq!(stats.misses, 500);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mada = font_ref(MADA);
+        let run = ShapedRun::default();
+        let entry_bytes = mem::size_of::<CacheKey>() + mem::size_of::<CacheEntry>() + 4;
+        let engine = Engine::new(N_SHARDS * 3 * entry_bytes);
+        let cache = &engine.cache;
+        // Keys of the same size that land in the first shard.
+        let keys: Vec<String> = (0..)
+            .map(|i| format!("{:04}", i))
+            .filter(|text| std::ptr::eq(cache.shard(&key(text, &mada)), &cache.shards[0]))
+            .take(4)
+            .collect();
+        let cached = |i: usize| cache.get(&key(&keys[i], &mada)).is_some();
+        for text in &keys[..3] {
+            cache.insert(key(text, &mada), run.clone());
+        }
+        assert!((0..3).all(cached));
+        // Use the first key again, so the second one is the oldest.
+        assert!(cached(0));
+        cache.insert(key(&keys[3], &mada), run.clone());
+        assert!(!cached(1));
+        assert!(cached(0) && cached(2) && cached(3));
+        assert_eq!(lock(&cache.shards[0]).bytes, 3 * entry_bytes);
+    }
+
+    #[test]
+    fn repeated_layout_hits() {
+        let fonts = collection(&[MADA]);
+        let style = TextStyle::default();
+        let text = "repeated_layout_hits";
+        let engine = Engine::new(1 << 20);
+        LayoutSession::create_with_shaper(text, &style, &fonts, engine.shaper());
+        let first = engine.stats();
+        assert_eq!(first.hits, 0);
+        assert!(first.misses > 0);
+        LayoutSession::create_with_shaper(text, &style, &fonts, engine.shaper());
+        let second = engine.stats();
+        assert_eq!((second.hits, second.misses), (first.misses, first.misses));
+
+        // `create` goes through the global engine. Other tests share it, so
+        // only check that this session's runs were hits.
+        LayoutSession::create(text, &style, &fonts);
+        let before = Engine::global().stats();
+        LayoutSession::create(text, &style, &fonts);
+        let after = Engine::global().stats();
+        assert!(after.hits - before.hits >= first.misses);
+    }
+}
@@ -0,0 +1,284 @@
+//! Fallback fonts for characters not covered by the collection.
+
+#[cfg(feature = "font-kit")]
+use std::cell::RefCell;
+#[cfg(feature = "font-kit")]
+use std::collections::HashMap;
+#[cfg(feature = "font-kit")]
+use std::rc::Rc;
+
+#[cfg(feature = "font-kit")]
+use font_kit::handle::Handle;
+#[cfg(feature = "font-kit")]
+use font_kit::properties::{Style, Weight};
+#[cfg(feature = "font-kit")]
+use font_kit::source::Source;
+
+use crate::script::{
+    Script, SCRIPT_BOPOMOFO, SCRIPT_HAN, SCRIPT_HANGUL, SCRIPT_HIRAGANA, SCRIPT_KATAKANA,
+};
+use crate::FontRef;
+
+/// A provider of fallback fonts.
+///
+/// When no family in a `FontCollection` covers a character, the collection
+/// asks its provider, and appends the first result as a fallback family.
+pub trait FallbackProvider {
+    /// Fonts covering `c`, best match first.
+    ///
+    /// The script is the one detected for the run containing the character,
+    /// and `locales` is the locale list from the style, in priority order.
+    /// The collection only takes the first font, so providers should load
+    /// fonts as the iterator is advanced rather than up front.
+    ///
+    /// Each face should be loaded once, and returned as clones of the same
+    /// `FontRef` after that, as that's how the collection tells whether it
+    /// already has the font.
+    fn fallbacks<'a>(
+        &'a self,
+        c: char,
+        script: Script,
+        locales: &[String],
+    ) -> Box<dyn Iterator<Item = FontRef> + 'a>;
+}
+
+/// A fallback provider that searches the fonts of a font-kit `Source`.
+///
+/// Typically this is a `SystemSource`, but a `MemSource` works too. The fonts
+/// are enumerated on the first query, and each one is loaded (and its
+/// coverage computed) when a query first gets to it.
+#[cfg(feature = "font-kit")]
+pub struct SourceFallback<S: Source> {
+    source: S,
+    handles: RefCell<Option<Rc<Vec<Handle>>>>,
+    // Fonts we've tried to load so far, by index into `handles`, so that a
+    // face is always returned as the same `FontRef`. None if loading failed.
+    loaded: RefCell<HashMap<usize, Option<FontRef>>>,
+}
+
+#[cfg(feature = "font-kit")]
+impl<S: Source> SourceFallback<S> {
+    pub fn new(source: S) -> SourceFallback<S> {
+        SourceFallback {
+            source,
+            handles: RefCell::new(None),
+            loaded: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn handles(&self) -> Rc<Vec<Handle>> {
+        self.handles
+            .borrow_mut()
+            .get_or_insert_with(|| match self.source.all_fonts() {
+                Ok(handles) => {
+                    debug!("found {} fallback candidates", handles.len());
+                    Rc::new(handles)
+                }
+                Err(e) => {
+                    warn!("error enumerating fallback fonts: {:?}", e);
+                    Rc::new(Vec::new())
+                }
+            })
+            .clone()
+    }
+
+    fn load(&self, ix: usize, handle: &Handle) -> Option<FontRef> {
+        self.loaded
+            .borrow_mut()
+            .entry(ix)
+            .or_insert_with(|| match handle.load() {
+                Ok(font) => Some(FontRef::new(font)),
+                Err(e) => {
+                    warn!("failed to load fallback font {:?}: {:?}", handle, e);
+                    None
+                }
+            })
+            .clone()
+    }
+}
+
+#[cfg(feature = "font-kit")]
+impl<S: Source> FallbackProvider for SourceFallback<S> {
+    fn fallbacks<'a>(
+        &'a self,
+        c: char,
+        script: Script,
+        locales: &[String],
+    ) -> Box<dyn Iterator<Item = FontRef> + 'a> {
+        let suffix = locale_for_script(locales, script).and_then(cjk_name_suffix);
+        Box::new(SourceCandidates {
+            fallback: self,
+            handles: self.handles(),
+            c,
+            suffix,
+            next: 0,
+            deferred: Vec::new(),
+            rest: None,
+        })
+    }
+}
+
+// Candidates in order of score, with source order breaking ties. A font with
+// the best score can be returned as soon as it's loaded; the others have to
+// wait until all the fonts have been seen.
+#[cfg(feature = "font-kit")]
+struct SourceCandidates<'a, S: Source> {
+    fallback: &'a SourceFallback<S>,
+    handles: Rc<Vec<Handle>>,
+    c: char,
+    suffix: Option<&'static str>,
+    next: usize,
+    deferred: Vec<(u32, FontRef)>,
+    rest: Option<std::vec::IntoIter<(u32, FontRef)>>,
+}
+
+#[cfg(feature = "font-kit")]
+impl<'a, S: Source> Iterator for SourceCandidates<'a, S> {
+    type Item = FontRef;
+
+    fn next(&mut self) -> Option<FontRef> {
+        while self.next < self.handles.len() {
+            let ix = self.next;
+            self.next += 1;
+            let font = match self.fallback.load(ix, &self.handles[ix]) {
+                Some(font) if font.supports_codepoint(self.c) => font,
+                _ => continue,
+            };
+            match candidate_score(&font, self.suffix) {
+                0 => return Some(font),
+                score => self.deferred.push((score, font)),
+            }
+        }
+        let deferred = &mut self.deferred;
+        let rest = self.rest.get_or_insert_with(|| {
+            // Stable sort, so source order breaks ties.
+            deferred.sort_by_key(|&(score, _)| score);
+            std::mem::take(deferred).into_iter()
+        });
+        rest.next().map(|(_, font)| font)
+    }
+}
+
+// Lower is better. Source metadata doesn't tell us which language a font is
+// intended for, so for Han unification we use the naming convention of the
+// common CJK families (Noto Sans CJK JP, Source Han Sans KR, etc).
+#[cfg(feature = "font-kit")]
+fn candidate_score(font: &FontRef, cjk_suffix: Option<&str>) -> u32 {
+    let mut score = 0;
+    let properties = font.font.properties();
+    if properties.style != Style::Normal {
+        score += 2;
+    }
+    if properties.weight != Weight::NORMAL {
+        score += 1;
+    }
+    if let Some(suffix) = cjk_suffix {
+        let name = font.font.family_name();
+        if !name.split_whitespace().any(|word| word == suffix) {
+            score += 4;
+        }
+    }
+    score
+}
+
+fn cjk_name_suffix(locale: &str) -> Option<&'static str> {
+    let mut subtags = locale.split(['-', '_']);
+    let lang = subtags.next()?;
+    let rest: Vec<&str> = subtags.collect();
+    let has = |tag: &str| rest.iter().any(|s| s.eq_ignore_ascii_case(tag));
+    match lang {
+        "ja" => Some("JP"),
+        "ko" => Some("KR"),
+        "zh" if has("Hant") || has("TW") => Some("TC"),
+        "zh" if has("HK") || has("MO") => Some("HK"),
+        "zh" => Some("SC"),
+        _ => None,
+    }
+}
+
+/// Choose the locale from the list that applies to the script.
+///
+/// Following the requirements doc, Han unification is controlled by the first
+/// CJK entry in the list. For other scripts, the first locale is used.
+pub(crate) fn locale_for_script(locales: &[String], script: Script) -> Option<&str> {
+    let is_cjk_script = [
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+    ]
+    .contains(&script);
+    if is_cjk_script {
+        if let Some(locale) = locales.iter().find(|l| cjk_name_suffix(l).is_some()) {
+            return Some(locale);
+        }
+    }
+    locales.first().map(|l| l.as_str())
+}
+
+#[cfg(all(test, feature = "font-kit"))]
+mod tests {
+    use std::sync::Arc;
+
+    use font_kit::sources::mem::MemSource;
+
+    use super::*;
+    use crate::script::SCRIPT_ARABIC;
+    use crate::test_util::{font_bytes, read_u16, read_u32, ESTEDAD, MADA};
+
+    // The test fonts are medium and black; this makes one regular, so it
+    // gets the best score.
+    fn regular(mut font: Vec<u8>) -> Vec<u8> {
+        let os2 = (12..12 + 16 * read_u16(&font, 4))
+            .step_by(16)
+            .find(|&record| &font[record..record + 4] == b"OS/2")
+            .map(|record| read_u32(&font, record + 8))
+            .unwrap();
+        font[os2 + 4..os2 + 6].copy_from_slice(&400u16.to_be_bytes());
+        font
+    }
+
+    // Sources order their fonts by family name, so Estedad comes first.
+    fn source_fallback(estedad: Vec<u8>, mada: Vec<u8>) -> SourceFallback<MemSource> {
+        let handles = vec![estedad, mada]
+            .into_iter()
+            .map(|data| Handle::from_memory(Arc::new(data), 0));
+        SourceFallback::new(MemSource::from_fonts(handles).unwrap())
+    }
+
+    fn families(fallback: &SourceFallback<MemSource>, c: char) -> Vec<String> {
+        fallback
+            .fallbacks(c, SCRIPT_ARABIC, &[])
+            .map(|font| font.font.family_name())
+            .collect()
+    }
+
+    #[test]
+    fn coverage() {
+        let fallback = source_fallback(font_bytes(ESTEDAD), font_bytes(MADA));
+        assert_eq!(families(&fallback, '\u{fb57}'), ["Estedad"]);
+        assert_eq!(families(&fallback, '\u{2074}'), ["Mada"]);
+        assert!(families(&fallback, '\u{4e00}').is_empty());
+        assert_eq!(families(&fallback, 'a'), ["Estedad", "Mada"]);
+    }
+
+    #[test]
+    fn prefers_regular() {
+        let fallback = source_fallback(font_bytes(ESTEDAD), regular(font_bytes(MADA)));
+        assert_eq!(families(&fallback, 'a'), ["Mada", "Estedad"]);
+    }
+
+    #[test]
+    fn loads_lazily() {
+        let fallback = source_fallback(regular(font_bytes(ESTEDAD)), font_bytes(MADA));
+        assert!(fallback.handles.borrow().is_none());
+        let first = fallback.fallbacks('a', SCRIPT_ARABIC, &[]).next().unwrap();
+        assert_eq!(first.font.family_name(), "Estedad");
+        assert_eq!(fallback.loaded.borrow().len(), 1);
+        let again = fallback.fallbacks('a', SCRIPT_ARABIC, &[]).next().unwrap();
+        assert!(Arc::ptr_eq(&first.font, &again.font));
+        assert_eq!(fallback.fallbacks('a', SCRIPT_ARABIC, &[]).count(), 2);
+        assert_eq!(fallback.loaded.borrow().len(), 2);
+    }
+}
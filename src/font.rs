@@ -0,0 +1,88 @@
+//! The font type and its properties.
+//!
+//! With the `font-kit` feature (the default) these are font-kit's types, for
+//! the platform's native loader. Otherwise they come from a small pure-Rust
+//! implementation on top of ttf-parser, with the same API.
+
+#[cfg(feature = "font-kit")]
+pub use font_kit::error::FontLoadingError;
+#[cfg(feature = "font-kit")]
+pub use font_kit::loaders::default::Font;
+#[cfg(feature = "font-kit")]
+pub use font_kit::properties::Style;
+
+#[cfg(not(feature = "font-kit"))]
+pub use crate::ttf_font::{Font, FontLoadingError, Style};
+
+use crate::font_data::FontData;
+
+const HEAD: u32 = u32::from_be_bytes(*b"head");
+
+/// The file data of a font and its face index, if we can get it without a
+/// copy.
+///
+/// FreeType keeps the file in memory, and shares it. Other font-kit loaders
+/// would make a copy, so callers have to supply the data themselves with
+/// `FontRef::with_data`, or fall back to `copy_data` when they need it.
+#[cfg(all(
+    feature = "font-kit",
+    not(any(target_os = "macos", target_os = "ios", target_family = "windows"))
+))]
+pub(crate) fn shared_data(font: &Font) -> Option<(FontData, u32)> {
+    copy_data(font)
+}
+
+#[cfg(all(
+    feature = "font-kit",
+    any(target_os = "macos", target_os = "ios", target_family = "windows")
+))]
+pub(crate) fn shared_data(_font: &Font) -> Option<(FontData, u32)> {
+    None
+}
+
+#[cfg(not(feature = "font-kit"))]
+pub(crate) fn shared_data(font: &Font) -> Option<(FontData, u32)> {
+    let (data, index) = font.data_and_index();
+    Some((data.clone(), index))
+}
+
+/// The file data of a font, as the loader gives it to us, and its face
+/// index.
+///
+/// font-kit doesn't tell us the face index, so we look for the face that
+/// matches the font.
+pub(crate) fn copy_data(font: &Font) -> Option<(FontData, u32)> {
+    let data = FontData::from_arc_vec(font.copy_font_data()?);
+    let index = (0..data.face_count()).find(|&index| data_matches(font, &data, index))?;
+    Some((data, index))
+}
+
+/// Load all the faces of `data`.
+///
+/// font-kit's loaders take a `Vec`, so a mapping is copied, once for all the
+/// faces.
+#[cfg(feature = "font-kit")]
+pub(crate) fn fonts_from_data(data: &FontData) -> Result<Vec<Font>, FontLoadingError> {
+    let bytes = data.to_arc_vec();
+    (0..data.face_count())
+        .map(|index| Font::from_bytes(bytes.clone(), index))
+        .collect()
+}
+
+#[cfg(not(feature = "font-kit"))]
+pub(crate) fn fonts_from_data(data: &FontData) -> Result<Vec<Font>, FontLoadingError> {
+    (0..data.face_count())
+        .map(|index| Font::from_data(data.clone(), index))
+        .collect()
+}
+
+/// Whether face `index` of `data` is the one `font` was loaded from.
+///
+/// The head table has the font's checksum and timestamps, so it's different
+/// for different fonts, even between the faces of a collection.
+pub(crate) fn data_matches(font: &Font, data: &FontData, index: u32) -> bool {
+    match (font.load_font_table(HEAD), data.table(index, HEAD)) {
+        (Some(head), Some(data_head)) => *head == *data_head,
+        _ => false,
+    }
+}
@@ -0,0 +1,168 @@
+//! Shared font data, for creating fonts and HarfBuzz blobs without copying.
+
+use std::fmt;
+#[cfg(feature = "harfbuzz")]
+use std::os::raw::{c_char, c_uint, c_void};
+use std::sync::Arc;
+
+#[cfg(feature = "mmap")]
+use std::fs::File;
+#[cfg(feature = "mmap")]
+use std::io;
+#[cfg(feature = "mmap")]
+use std::path::Path;
+
+#[cfg(feature = "harfbuzz")]
+use harfbuzz::sys::{hb_blob_create, HB_MEMORY_MODE_READONLY};
+#[cfg(feature = "harfbuzz")]
+use harfbuzz::Blob;
+
+/// The bytes of a font file, shared between the loader and the shaper.
+///
+/// Cloning is cheap; all clones refer to the same buffer.
+#[derive(Clone)]
+pub struct FontData {
+    inner: Inner,
+}
+
+#[derive(Clone)]
+enum Inner {
+    Vec(Arc<Vec<u8>>),
+    #[cfg(feature = "mmap")]
+    Mmap(Arc<memmap2::Mmap>),
+}
+
+impl FontData {
+    /// Share an in-memory buffer, such as the one passed to `Font::from_bytes`.
+    pub fn from_arc_vec(data: Arc<Vec<u8>>) -> FontData {
+        FontData {
+            inner: Inner::Vec(data),
+        }
+    }
+
+    /// Map a font file into memory.
+    ///
+    /// The file must not be modified while it is mapped; as with any mmap,
+    /// doing so is undefined behavior.
+    ///
+    /// See `FontCollectionBuilder::add_mapped_file` for loading the faces.
+    /// font-kit's loaders need the data in a `Vec`, so with the `font-kit`
+    /// feature the loader has its own copy, shared by all the faces, and only
+    /// the shaper uses the mapping.
+    #[cfg(feature = "mmap")]
+    pub fn map_file(path: impl AsRef<Path>) -> io::Result<FontData> {
+        let file = File::open(path)?;
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(FontData {
+            inner: Inner::Mmap(Arc::new(mmap)),
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match &self.inner {
+            Inner::Vec(data) => data,
+            #[cfg(feature = "mmap")]
+            Inner::Mmap(mmap) => mmap,
+        }
+    }
+
+    /// The number of faces, which is more than one for a collection file.
+    ///
+    /// This only reads the header, so it's not a check that the data is a
+    /// valid font.
+    pub fn face_count(&self) -> u32 {
+        let bytes = self.as_bytes();
+        if read_u32(bytes, 0) == Some(TTC_TAG) {
+            read_u32(bytes, 8).unwrap_or(0)
+        } else {
+            1
+        }
+    }
+
+    /// The data as a `Vec`, for loaders that need one. An in-memory buffer
+    /// is shared, but a mapping has to be copied.
+    pub(crate) fn to_arc_vec(&self) -> Arc<Vec<u8>> {
+        match &self.inner {
+            Inner::Vec(data) => data.clone(),
+            #[cfg(feature = "mmap")]
+            Inner::Mmap(mmap) => Arc::new(mmap.to_vec()),
+        }
+    }
+
+    /// The table with the given tag in face `index`, read from the table
+    /// directory.
+    pub(crate) fn table(&self, index: u32, tag: u32) -> Option<&[u8]> {
+        let bytes = self.as_bytes();
+        let dir = if read_u32(bytes, 0)? == TTC_TAG {
+            if index >= read_u32(bytes, 8)? {
+                return None;
+            }
+            read_u32(bytes, 12 + 4 * index as usize)? as usize
+        } else if index == 0 {
+            0
+        } else {
+            return None;
+        };
+        let n_tables = read_u16(bytes, dir + 4)?;
+        for i in 0..n_tables as usize {
+            let record = dir + 12 + 16 * i;
+            if read_u32(bytes, record)? == tag {
+                let offset = read_u32(bytes, record + 8)? as usize;
+                let len = read_u32(bytes, record + 12)? as usize;
+                return bytes.get(offset..offset.checked_add(len)?);
+            }
+        }
+        None
+    }
+
+    /// Create a HarfBuzz blob referring to the data.
+    ///
+    /// The blob holds a reference to the buffer, which is released by
+    /// HarfBuzz's destroy callback, so the blob (and any face created from
+    /// it) can outlive `self`.
+    #[cfg(feature = "harfbuzz")]
+    pub(crate) fn to_blob(&self) -> Blob<'static> {
+        let bytes = self.as_bytes();
+        assert!(bytes.len() <= c_uint::MAX as usize);
+        let user_data = Box::into_raw(Box::new(self.clone()));
+        unsafe {
+            let hb_blob = hb_blob_create(
+                bytes.as_ptr() as *const c_char,
+                bytes.len() as c_uint,
+                HB_MEMORY_MODE_READONLY,
+                user_data as *mut c_void,
+                Some(font_data_blob_destroy),
+            );
+            Blob::from_raw(hb_blob)
+        }
+    }
+}
+
+const TTC_TAG: u32 = u32::from_be_bytes(*b"ttcf");
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    let bytes = bytes.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let bytes = bytes.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+// This has type hb_destroy_func_t.
+#[cfg(feature = "harfbuzz")]
+unsafe extern "C" fn font_data_blob_destroy(user_data: *mut c_void) {
+    drop(Box::from_raw(user_data as *mut FontData));
+}
+
+impl fmt::Debug for FontData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match &self.inner {
+            Inner::Vec(_) => "Vec",
+            #[cfg(feature = "mmap")]
+            Inner::Mmap(_) => "Mmap",
+        };
+        write!(f, "FontData({}, {} bytes)", kind, self.as_bytes().len())
+    }
+}
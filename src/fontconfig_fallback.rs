@@ -0,0 +1,328 @@
+//! Fallback through fontconfig, for Linux and other fontconfig platforms.
+//!
+//! Fontconfig can order fonts by language as well as coverage, which is what
+//! we need for Han unification. See docs/script_matching.md for background.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::rc::Rc;
+
+use fontconfig::fontconfig::{
+    FcChar8, FcCharSet, FcCharSetCopy, FcCharSetDestroy, FcCharSetHasChar, FcConfig,
+    FcConfigBuildFonts, FcConfigCreate, FcConfigDestroy, FcConfigParseAndLoad, FcConfigSubstitute,
+    FcDefaultSubstitute, FcFontSetDestroy, FcFontSort, FcInitLoadConfigAndFonts, FcMatchPattern,
+    FcPattern, FcPatternAddString, FcPatternCreate, FcPatternDestroy, FcPatternGetCharSet,
+    FcPatternGetInteger, FcPatternGetString, FcResultMatch,
+};
+
+use crate::fallback::{locale_for_script, FallbackProvider};
+use crate::script::Script;
+use crate::{Font, FontRef};
+
+const FC_FILE: &[u8] = b"file\0";
+const FC_INDEX: &[u8] = b"index\0";
+const FC_LANG: &[u8] = b"lang\0";
+const FC_CHARSET: &[u8] = b"charset\0";
+
+const FC_FALSE: c_int = 0;
+const FC_TRUE: c_int = 1;
+
+/// A fallback provider backed by fontconfig.
+///
+/// The fonts are ordered by `FcFontSort` for the `lang` of the locale that
+/// applies to the script, so the configuration's language preferences (for
+/// example, which CJK font to use for `ja` vs `zh-cn`) are respected.
+pub struct FontconfigFallback {
+    config: *mut FcConfig,
+    // Sorted candidates for each fontconfig lang (None for no lang).
+    sorted: RefCell<HashMap<Option<String>, Rc<Vec<Candidate>>>>,
+    // Fonts we've loaded so far, by file and face index, so that a face is
+    // always returned as the same `FontRef`.
+    loaded: RefCell<HashMap<(PathBuf, u32), FontRef>>,
+}
+
+struct Candidate {
+    path: PathBuf,
+    index: u32,
+    charset: *mut FcCharSet,
+}
+
+impl FontconfigFallback {
+    /// Create a provider using the system fontconfig configuration.
+    pub fn new() -> Option<FontconfigFallback> {
+        let config = unsafe { FcInitLoadConfigAndFonts() };
+        FontconfigFallback::from_raw_config(config)
+    }
+
+    /// Create a provider from a specific fonts.conf file.
+    ///
+    /// This is mostly useful for tests, where the config can point at a
+    /// directory of known fonts.
+    pub fn from_config_file(path: impl AsRef<Path>) -> Option<FontconfigFallback> {
+        let path = CString::new(path.as_ref().to_str()?).ok()?;
+        unsafe {
+            let config = FcConfigCreate();
+            if config.is_null() {
+                return None;
+            }
+            if FcConfigParseAndLoad(config, path.as_ptr() as *const FcChar8, FC_TRUE) == FC_FALSE
+                || FcConfigBuildFonts(config) == FC_FALSE
+            {
+                FcConfigDestroy(config);
+                return None;
+            }
+            FontconfigFallback::from_raw_config(config)
+        }
+    }
+
+    fn from_raw_config(config: *mut FcConfig) -> Option<FontconfigFallback> {
+        if config.is_null() {
+            return None;
+        }
+        Some(FontconfigFallback {
+            config,
+            sorted: RefCell::new(HashMap::new()),
+            loaded: RefCell::new(HashMap::new()),
+        })
+    }
+
+    fn sort_for_lang(&self, lang: Option<&str>) -> Vec<Candidate> {
+        let mut candidates = Vec::new();
+        unsafe {
+            let pattern = FcPatternCreate();
+            if let Some(lang) = lang.and_then(|l| CString::new(l).ok()) {
+                FcPatternAddString(pattern, c_str(FC_LANG), lang.as_ptr() as *const FcChar8);
+            }
+            FcConfigSubstitute(self.config, pattern, FcMatchPattern);
+            FcDefaultSubstitute(pattern);
+            let mut result = 0;
+            let font_set = FcFontSort(self.config, pattern, FC_FALSE, ptr::null_mut(), &mut result);
+            FcPatternDestroy(pattern);
+            if font_set.is_null() {
+                return candidates;
+            }
+            let fonts = (*font_set).fonts;
+            for i in 0..(*font_set).nfont as usize {
+                let font = *fonts.add(i);
+                if let Some(candidate) = candidate_from_pattern(font) {
+                    candidates.push(candidate);
+                }
+            }
+            FcFontSetDestroy(font_set);
+        }
+        debug!(
+            "fontconfig sorted {} fonts for lang {:?}",
+            candidates.len(),
+            lang
+        );
+        candidates
+    }
+
+    fn load(&self, candidate: &Candidate) -> Option<FontRef> {
+        let key = (candidate.path.clone(), candidate.index);
+        let mut loaded = self.loaded.borrow_mut();
+        if let Some(font) = loaded.get(&key) {
+            return Some(font.clone());
+        }
+        match Font::from_path(&candidate.path, candidate.index) {
+            Ok(font) => {
+                let font = FontRef::new(font);
+                loaded.insert(key, font.clone());
+                Some(font)
+            }
+            Err(e) => {
+                warn!("failed to load {:?}: {:?}", candidate.path, e);
+                None
+            }
+        }
+    }
+}
+
+impl FallbackProvider for FontconfigFallback {
+    fn fallbacks<'a>(
+        &'a self,
+        c: char,
+        script: Script,
+        locales: &[String],
+    ) -> Box<dyn Iterator<Item = FontRef> + 'a> {
+        let lang = locale_for_script(locales, script).map(fc_lang);
+        let candidates = self
+            .sorted
+            .borrow_mut()
+            .entry(lang.clone())
+            .or_insert_with(|| Rc::new(self.sort_for_lang(lang.as_deref())))
+            .clone();
+        // Fonts are only loaded when the iterator gets to them.
+        Box::new((0..candidates.len()).filter_map(move |i| {
+            let candidate = &candidates[i];
+            if unsafe { FcCharSetHasChar(candidate.charset, c as u32) } == 0 {
+                return None;
+            }
+            self.load(candidate)
+        }))
+    }
+}
+
+impl Drop for FontconfigFallback {
+    fn drop(&mut self) {
+        unsafe {
+            for candidates in self.sorted.get_mut().values() {
+                for candidate in candidates.iter() {
+                    FcCharSetDestroy(candidate.charset);
+                }
+            }
+            FcConfigDestroy(self.config);
+        }
+    }
+}
+
+fn c_str(s: &[u8]) -> *const c_char {
+    s.as_ptr() as *const c_char
+}
+
+unsafe fn candidate_from_pattern(pattern: *mut FcPattern) -> Option<Candidate> {
+    let mut file: *mut FcChar8 = ptr::null_mut();
+    if FcPatternGetString(pattern, c_str(FC_FILE), 0, &mut file) != FcResultMatch {
+        return None;
+    }
+    let path = CStr::from_ptr(file as *const c_char).to_str().ok()?;
+    let mut index = 0;
+    if FcPatternGetInteger(pattern, c_str(FC_INDEX), 0, &mut index) != FcResultMatch {
+        index = 0;
+    }
+    let mut charset: *mut FcCharSet = ptr::null_mut();
+    if FcPatternGetCharSet(pattern, c_str(FC_CHARSET), 0, &mut charset) != FcResultMatch {
+        return None;
+    }
+    Some(Candidate {
+        path: PathBuf::from(path),
+        index: index as u32,
+        charset: FcCharSetCopy(charset),
+    })
+}
+
+/// Convert a BCP-47 tag into fontconfig's RFC 3066 style lang.
+///
+/// Fontconfig has no notion of script subtags, so for Chinese we infer the
+/// region from the script, as described in docs/script_matching.md.
+fn fc_lang(locale: &str) -> String {
+    let mut subtags = locale.split(['-', '_']);
+    let lang = subtags.next().unwrap_or_default().to_ascii_lowercase();
+    let mut script = None;
+    let mut region = None;
+    for subtag in subtags {
+        match subtag.len() {
+            2 if region.is_none() => region = Some(subtag.to_ascii_lowercase()),
+            4 if script.is_none() => script = Some(subtag.to_ascii_lowercase()),
+            _ => (),
+        }
+    }
+    if lang == "zh" && region.is_none() {
+        region = match script.as_deref() {
+            Some("hant") => Some("tw".to_string()),
+            Some("hans") => Some("cn".to_string()),
+            _ => None,
+        };
+    }
+    match region {
+        Some(region) => format!("{}-{}", lang, region),
+        None => lang,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::process;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::script::{SCRIPT_ARABIC, SCRIPT_HAN};
+    use crate::test_util::fonts_dir;
+
+    // A provider with only the fonts in testdata/fonts.
+    fn test_fallback(name: &str) -> FontconfigFallback {
+        let dir = env::temp_dir().join(format!("skribo-{}-{}", name, process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let conf = dir.join("fonts.conf");
+        fs::write(
+            &conf,
+            format!(
+                "<?xml version=\"1.0\"?>\n\
+                 <!DOCTYPE fontconfig SYSTEM \"fonts.dtd\">\n\
+                 <fontconfig>\n  <dir>{}</dir>\n  <cachedir>{}</cachedir>\n</fontconfig>\n",
+                fonts_dir().display(),
+                dir.display()
+            ),
+        )
+        .unwrap();
+        let fallback = FontconfigFallback::from_config_file(&conf).unwrap();
+        // The fonts are scanned when the config is loaded.
+        fs::remove_dir_all(&dir).unwrap();
+        fallback
+    }
+
+    fn first_family(fallback: &FontconfigFallback, c: char) -> Option<String> {
+        let font = fallback.fallbacks(c, SCRIPT_ARABIC, &[]).next()?;
+        Some(font.font.family_name())
+    }
+
+    #[test]
+    fn coverage() {
+        let fallback = test_fallback("coverage");
+        // Arabic presentation forms are only in Estedad, and superscript
+        // four only in Mada.
+        assert_eq!(
+            first_family(&fallback, '\u{fb57}').as_deref(),
+            Some("Estedad")
+        );
+        assert_eq!(first_family(&fallback, '\u{2074}').as_deref(), Some("Mada"));
+        assert_eq!(first_family(&fallback, '\u{4e00}'), None);
+    }
+
+    #[test]
+    fn loads_lazily() {
+        let fallback = test_fallback("lazy");
+        let first = fallback.fallbacks('a', SCRIPT_ARABIC, &[]).next().unwrap();
+        assert_eq!(fallback.loaded.borrow().len(), 1);
+        let again = fallback.fallbacks('a', SCRIPT_ARABIC, &[]).next().unwrap();
+        assert!(Arc::ptr_eq(&first.font, &again.font));
+        assert!(fallback.fallbacks('a', SCRIPT_ARABIC, &[]).count() >= 2);
+    }
+
+    #[test]
+    fn lang_from_locale() {
+        assert_eq!(fc_lang("zh-Hant"), "zh-tw");
+        assert_eq!(fc_lang("zh-Hans"), "zh-cn");
+        assert_eq!(fc_lang("zh-Hant-HK"), "zh-hk");
+        assert_eq!(fc_lang("zh"), "zh");
+        assert_eq!(fc_lang("ja-JP"), "ja-jp");
+        assert_eq!(fc_lang("ja_JP"), "ja-jp");
+        assert_eq!(fc_lang("sr-Latn-RS"), "sr-rs");
+        assert_eq!(fc_lang("AR"), "ar");
+    }
+
+    #[test]
+    fn sorts_for_locale() {
+        let fallback = test_fallback("locale");
+        let locales = ["en-US".to_string(), "zh-Hant".to_string()];
+        let font = fallback.fallbacks('a', SCRIPT_HAN, &locales).next();
+        assert!(font.is_some());
+        // Han unification follows the first CJK locale; other scripts the
+        // first locale.
+        let font = fallback.fallbacks('a', SCRIPT_ARABIC, &locales).next();
+        assert!(font.is_some());
+        let sorted = fallback.sorted.borrow();
+        let mut langs: Vec<_> = sorted.keys().cloned().collect();
+        langs.sort();
+        assert_eq!(
+            langs,
+            [Some("en-us".to_string()), Some("zh-tw".to_string())]
+        );
+    }
+}
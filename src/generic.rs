@@ -0,0 +1,434 @@
+//! Resolution of CSS generic family names to concrete families.
+
+#[cfg(feature = "font-kit")]
+use font_kit::family_name::FamilyName;
+#[cfg(feature = "font-kit")]
+use font_kit::properties::Properties;
+#[cfg(feature = "font-kit")]
+use font_kit::source::Source;
+
+use crate::collection::lang_matches;
+use crate::script::Script;
+#[cfg(feature = "font-kit")]
+use crate::script::{SCRIPT_HAN, SCRIPT_HANGUL};
+
+/// A CSS generic font family.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GenericFamily {
+    Serif,
+    SansSerif,
+    Monospace,
+    Cursive,
+    Fantasy,
+    SystemUi,
+    Emoji,
+}
+
+impl GenericFamily {
+    /// Parse a CSS generic family keyword, such as "sans-serif".
+    pub fn from_name(name: &str) -> Option<GenericFamily> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "serif" => Some(GenericFamily::Serif),
+            "sans-serif" => Some(GenericFamily::SansSerif),
+            "monospace" => Some(GenericFamily::Monospace),
+            "cursive" => Some(GenericFamily::Cursive),
+            "fantasy" => Some(GenericFamily::Fantasy),
+            "system-ui" => Some(GenericFamily::SystemUi),
+            "emoji" => Some(GenericFamily::Emoji),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "font-kit")]
+    fn font_kit_name(self) -> Option<FamilyName> {
+        match self {
+            GenericFamily::Serif => Some(FamilyName::Serif),
+            GenericFamily::SansSerif => Some(FamilyName::SansSerif),
+            GenericFamily::Monospace => Some(FamilyName::Monospace),
+            GenericFamily::Cursive => Some(FamilyName::Cursive),
+            GenericFamily::Fantasy => Some(FamilyName::Fantasy),
+            GenericFamily::SystemUi | GenericFamily::Emoji => None,
+        }
+    }
+}
+
+struct Entry {
+    generic: GenericFamily,
+    script: Option<Script>,
+    locale: Option<String>,
+    families: Vec<String>,
+}
+
+/// Maps generic families to concrete family names, per script and locale.
+///
+/// A mapping can be general (no script or locale), or specific to a script,
+/// a locale, or both. Lookups use the most specific mapping that applies.
+#[derive(Default)]
+pub struct GenericFamilyResolver {
+    entries: Vec<Entry>,
+}
+
+// (generic, script, locale, candidate families)
+#[cfg(feature = "font-kit")]
+type PlatformDefault = (
+    GenericFamily,
+    Option<Script>,
+    Option<&'static str>,
+    &'static [&'static str],
+);
+
+// Per-platform defaults for the generics font-kit doesn't know about, and for
+// Han unification. Only families present in the source are used.
+#[cfg(all(feature = "font-kit", target_os = "macos"))]
+const PLATFORM_DEFAULTS: &[PlatformDefault] = &[
+    (
+        GenericFamily::SystemUi,
+        None,
+        None,
+        &[".AppleSystemUIFont", "Helvetica Neue"],
+    ),
+    (GenericFamily::Emoji, None, None, &["Apple Color Emoji"]),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HAN),
+        Some("ja"),
+        &["Hiragino Sans"],
+    ),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HAN),
+        Some("zh-Hans"),
+        &["PingFang SC"],
+    ),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HAN),
+        Some("zh-Hant"),
+        &["PingFang TC"],
+    ),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HANGUL),
+        Some("ko"),
+        &["Apple SD Gothic Neo"],
+    ),
+];
+#[cfg(all(feature = "font-kit", target_family = "windows"))]
+const PLATFORM_DEFAULTS: &[PlatformDefault] = &[
+    (GenericFamily::SystemUi, None, None, &["Segoe UI"]),
+    (GenericFamily::Emoji, None, None, &["Segoe UI Emoji"]),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HAN),
+        Some("ja"),
+        &["Yu Gothic", "Meiryo"],
+    ),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HAN),
+        Some("zh-Hans"),
+        &["Microsoft YaHei"],
+    ),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HAN),
+        Some("zh-Hant"),
+        &["Microsoft JhengHei"],
+    ),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HANGUL),
+        Some("ko"),
+        &["Malgun Gothic"],
+    ),
+];
+#[cfg(all(
+    feature = "font-kit",
+    not(any(target_os = "macos", target_family = "windows"))
+))]
+const PLATFORM_DEFAULTS: &[PlatformDefault] = &[
+    (
+        GenericFamily::SystemUi,
+        None,
+        None,
+        &["Cantarell", "Ubuntu", "Noto Sans"],
+    ),
+    (
+        GenericFamily::Emoji,
+        None,
+        None,
+        &["Noto Color Emoji", "Twemoji", "EmojiOne"],
+    ),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HAN),
+        Some("ja"),
+        &["Noto Sans CJK JP"],
+    ),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HAN),
+        Some("zh-Hans"),
+        &["Noto Sans CJK SC"],
+    ),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HAN),
+        Some("zh-Hant"),
+        &["Noto Sans CJK TC"],
+    ),
+    (
+        GenericFamily::SansSerif,
+        Some(SCRIPT_HANGUL),
+        Some("ko"),
+        &["Noto Sans CJK KR"],
+    ),
+    (
+        GenericFamily::Serif,
+        Some(SCRIPT_HAN),
+        Some("ja"),
+        &["Noto Serif CJK JP"],
+    ),
+    (
+        GenericFamily::Serif,
+        Some(SCRIPT_HAN),
+        Some("zh-Hans"),
+        &["Noto Serif CJK SC"],
+    ),
+    (
+        GenericFamily::Serif,
+        Some(SCRIPT_HAN),
+        Some("zh-Hant"),
+        &["Noto Serif CJK TC"],
+    ),
+    (
+        GenericFamily::Serif,
+        Some(SCRIPT_HANGUL),
+        Some("ko"),
+        &["Noto Serif CJK KR"],
+    ),
+];
+
+impl GenericFamilyResolver {
+    /// Create an empty resolver.
+    pub fn new() -> GenericFamilyResolver {
+        GenericFamilyResolver::default()
+    }
+
+    /// Create a resolver with defaults from a font source.
+    ///
+    /// The general mappings for serif, sans-serif, monospace, cursive and
+    /// fantasy come from the source's own matching; system-ui, emoji and the
+    /// CJK mappings come from a per-platform list, filtered to the families
+    /// the source has.
+    #[cfg(feature = "font-kit")]
+    pub fn from_source(source: &dyn Source) -> GenericFamilyResolver {
+        let mut resolver = GenericFamilyResolver::new();
+        let generics = [
+            GenericFamily::Serif,
+            GenericFamily::SansSerif,
+            GenericFamily::Monospace,
+            GenericFamily::Cursive,
+            GenericFamily::Fantasy,
+        ];
+        for &generic in &generics {
+            let name = generic.font_kit_name().unwrap();
+            let family = source
+                .select_best_match(&[name], &Properties::new())
+                .ok()
+                .and_then(|handle| handle.load().ok())
+                .map(|font| font.family_name());
+            if let Some(family) = family {
+                resolver.set(generic, None, None, vec![family]);
+            }
+        }
+        for &(generic, script, locale, candidates) in PLATFORM_DEFAULTS {
+            let families: Vec<String> = candidates
+                .iter()
+                .filter(|name| source.select_family_by_name(name).is_ok())
+                .map(|name| name.to_string())
+                .collect();
+            if !families.is_empty() {
+                resolver.set(generic, script, locale, families);
+            }
+        }
+        if resolver
+            .resolve(GenericFamily::SystemUi, None, None)
+            .is_empty()
+        {
+            let sans_serif = resolver
+                .resolve(GenericFamily::SansSerif, None, None)
+                .to_vec();
+            resolver.set(GenericFamily::SystemUi, None, None, sans_serif);
+        }
+        resolver
+    }
+
+    /// Set the families for a generic, replacing any existing mapping with
+    /// the same script and locale.
+    pub fn set(
+        &mut self,
+        generic: GenericFamily,
+        script: Option<Script>,
+        locale: Option<&str>,
+        families: Vec<String>,
+    ) {
+        let locale = locale.map(String::from);
+        let existing = self
+            .entries
+            .iter_mut()
+            .find(|e| e.generic == generic && e.script == script && e.locale == locale);
+        match existing {
+            Some(entry) => entry.families = families,
+            None => self.entries.push(Entry {
+                generic,
+                script,
+                locale,
+                families,
+            }),
+        }
+    }
+
+    /// The concrete families for a generic in the given script and locale.
+    ///
+    /// Mappings matching both script and locale are preferred, then script
+    /// only, then locale only, then the general mapping.
+    pub fn resolve(
+        &self,
+        generic: GenericFamily,
+        script: Option<Script>,
+        locale: Option<&str>,
+    ) -> &[String] {
+        let applies = |entry: &&Entry| {
+            entry.generic == generic
+                && (entry.script.is_none() || entry.script == script)
+                && match (&entry.locale, locale) {
+                    (None, _) => true,
+                    (Some(a), Some(b)) => lang_matches(a, b),
+                    (Some(_), None) => false,
+                }
+        };
+        let specificity =
+            |entry: &&Entry| (entry.script.is_some() as u8) * 2 + (entry.locale.is_some() as u8);
+        self.entries
+            .iter()
+            .filter(applies)
+            .max_by_key(specificity)
+            .map(|entry| entry.families.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// All mappings for a generic, general mapping first.
+    ///
+    /// The locale of each mapping is returned so that it can be recorded as
+    /// language metadata on the resulting families.
+    #[cfg(feature = "font-kit")]
+    pub(crate) fn expand(&self, generic: GenericFamily) -> Vec<(Option<&str>, &[String])> {
+        let mut result: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|e| e.generic == generic)
+            .collect();
+        result.sort_by_key(|e| e.script.is_some() || e.locale.is_some());
+        result
+            .into_iter()
+            .map(|e| (e.locale.as_deref(), e.families.as_slice()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::script::{SCRIPT_HAN, SCRIPT_LATIN};
+
+    fn families(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn resolve_prefers_specific_mappings() {
+        let sans = GenericFamily::SansSerif;
+        let mut resolver = GenericFamilyResolver::new();
+        resolver.set(sans, None, None, families(&["General"]));
+        resolver.set(sans, None, Some("ja"), families(&["Locale"]));
+        resolver.set(sans, Some(SCRIPT_HAN), None, families(&["Script"]));
+        resolver.set(sans, Some(SCRIPT_HAN), Some("ja"), families(&["Both"]));
+        assert_eq!(
+            resolver.resolve(sans, Some(SCRIPT_HAN), Some("ja-JP")),
+            ["Both"]
+        );
+        assert_eq!(
+            resolver.resolve(sans, Some(SCRIPT_HAN), Some("ko")),
+            ["Script"]
+        );
+        assert_eq!(resolver.resolve(sans, Some(SCRIPT_HAN), None), ["Script"]);
+        assert_eq!(
+            resolver.resolve(sans, Some(SCRIPT_LATIN), Some("ja")),
+            ["Locale"]
+        );
+        assert_eq!(resolver.resolve(sans, None, Some("ja")), ["Locale"]);
+        assert_eq!(
+            resolver.resolve(sans, Some(SCRIPT_LATIN), Some("ko")),
+            ["General"]
+        );
+        assert_eq!(resolver.resolve(sans, None, None), ["General"]);
+        assert!(resolver
+            .resolve(GenericFamily::Serif, None, None)
+            .is_empty());
+
+        resolver.set(sans, None, None, families(&["Replaced"]));
+        assert_eq!(resolver.resolve(sans, None, None), ["Replaced"]);
+    }
+
+    #[cfg(feature = "font-kit")]
+    #[test]
+    fn family_list_expands_generics() {
+        use std::sync::Arc;
+
+        use font_kit::handle::Handle;
+        use font_kit::sources::mem::MemSource;
+
+        use crate::script::SCRIPT_ARABIC;
+        use crate::test_util::{font_bytes, ESTEDAD, MADA};
+        use crate::FontCollection;
+
+        let handles = vec![font_bytes(MADA), font_bytes(ESTEDAD)]
+            .into_iter()
+            .map(|data| Handle::from_memory(Arc::new(data), 0));
+        let source = MemSource::from_fonts(handles).unwrap();
+        let sans = GenericFamily::SansSerif;
+        let mut resolver = GenericFamilyResolver::new();
+        resolver.set(
+            sans,
+            Some(SCRIPT_ARABIC),
+            Some("fa"),
+            families(&["Estedad"]),
+        );
+        resolver.set(sans, None, None, families(&["Missing", "Mada"]));
+
+        let collection = FontCollection::from_family_list(
+            &["Nonexistent", "sans-serif", "Mada", "Estedad"],
+            &resolver,
+            &source,
+        );
+        let result: Vec<(String, Vec<String>)> = collection
+            .families
+            .iter()
+            .map(|family| {
+                let name = family.default_font().unwrap().font.family_name();
+                (name, family.lang().to_vec())
+            })
+            .collect();
+        // The general mapping comes first; "Mada" is already there, and
+        // "Estedad" is added again without the locale.
+        assert_eq!(
+            result,
+            [
+                ("Mada".to_string(), vec![]),
+                ("Estedad".to_string(), vec!["fa".to_string()]),
+                ("Estedad".to_string(), vec![]),
+            ]
+        );
+    }
+}
@@ -1,61 +1,107 @@
 //! A HarfBuzz shaping back-end.
 
 use pathfinder_geometry::vector::{Vector2F, vec2i};
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::os::raw::{c_char, c_int, c_uint, c_void};
+use std::ptr;
+use std::sync::Arc;
 
 use harfbuzz::sys::{
-    hb_buffer_get_glyph_infos,
-    hb_buffer_get_glyph_positions, hb_face_create, hb_face_destroy, hb_face_reference, hb_face_t,
-    hb_font_create, hb_font_destroy, hb_position_t, hb_shape,
-};
-use harfbuzz::{Blob, Buffer, Direction, Language};
-use harfbuzz::sys::{
-    hb_glyph_info_get_glyph_flags, hb_script_t, HB_GLYPH_FLAG_UNSAFE_TO_BREAK,
-    HB_SCRIPT_DEVANAGARI,
+    hb_blob_create, hb_blob_t, hb_buffer_add_utf8, hb_buffer_get_glyph_infos,
+    hb_buffer_get_glyph_positions, hb_face_create, hb_face_create_for_tables, hb_face_destroy,
+    hb_face_reference, hb_face_t, hb_font_create, hb_font_destroy, hb_position_t, hb_shape,
+    hb_feature_t, hb_tag_t, HB_MEMORY_MODE_READONLY,
 };
+use harfbuzz::{Buffer, Direction as HbDirection, Language};
+use harfbuzz::sys::{hb_glyph_info_get_glyph_flags, HB_GLYPH_FLAG_UNSAFE_TO_BREAK};
 
-use crate::collection::FontId;
-use crate::session::{FragmentGlyph, LayoutFragment};
+use crate::shaper::{
+    context_after, context_before, Direction, ShapeParams, ShapedGlyph, ShapedRun, Shaper,
+};
+use crate::font_data::FontData;
 use crate::unicode_funcs::install_unicode_funcs;
-use crate::{FontRef, Glyph, Layout, TextStyle};
-
-thread_local! {
-    static HB_THREAD_DATA: RefCell<HbThreadData> = RefCell::new(HbThreadData::new());
-}
+use crate::{Font, FontRef};
 
-// Per-thread data for HarfBuzz.
-struct HbThreadData {
-    hb_face_cache: HashMap<FontId, HbFace>,
+pub(crate) struct HbFace {
+    hb_face: *mut hb_face_t,
 }
 
-impl HbThreadData {
-    fn new() -> HbThreadData {
-        HbThreadData { hb_face_cache: HashMap::new() }
+impl HbFace {
+    /// The face for a font, created on first use.
+    fn get(font: &FontRef) -> &HbFace {
+        font.hb_face.get_or_init(|| HbFace::new(font))
     }
 
-    fn create_hb_face_for_font(&mut self, font: &FontRef) -> HbFace {
-        (*self.hb_face_cache.entry(FontId::from_font(font)).or_insert_with(|| {
-            HbFace::new(font)
-        })).clone()
+    fn new(font: &FontRef) -> HbFace {
+        match font.data() {
+            Some((data, index)) => HbFace::from_data(data, index),
+            // Rather than copying the whole file, only the tables HarfBuzz
+            // asks for are copied.
+            None => HbFace::new_for_tables(font),
+        }
     }
-}
 
-pub(crate) struct HbFace {
-    hb_face: *mut hb_face_t,
-}
+    fn from_data(data: &FontData, index: u32) -> HbFace {
+        let blob = data.to_blob();
+        unsafe {
+            let hb_face = hb_face_create(blob.as_raw(), index);
+            HbFace { hb_face }
+        }
+    }
 
-impl HbFace {
-    fn new(font: &FontRef) -> HbFace {
-        let data = font.font.copy_font_data().expect("font data unavailable");
-        let blob = Blob::new_from_arc_vec(data);
+    // Create a face that fetches each table through the loader, for fonts
+    // where we don't have the file data. HarfBuzz loads tables lazily, and
+    // keeps them for the lifetime of the face.
+    //
+    // This holds the font rather than the `FontRef`, which holds the face.
+    fn new_for_tables(font: &FontRef) -> HbFace {
+        let user_data = Box::into_raw(Box::new(font.font.clone()));
         unsafe {
-            let hb_face = hb_face_create(blob.as_raw(), 0);
+            let hb_face = hb_face_create_for_tables(
+                Some(font_table_func),
+                user_data as *mut c_void,
+                Some(font_destroy),
+            );
             HbFace { hb_face }
         }
     }
 }
 
+// Callback to access table data in a font.
+unsafe extern "C" fn font_table_func(
+    _: *mut hb_face_t,
+    tag: hb_tag_t,
+    user_data: *mut c_void,
+) -> *mut hb_blob_t {
+    let font = &*(user_data as *const Arc<Font>);
+    // Tag 0 asks for the whole file, which we can't provide.
+    if tag == 0 {
+        return ptr::null_mut();
+    }
+    match font.load_font_table(tag) {
+        Some(table) => {
+            let len = table.len();
+            assert!(len <= c_uint::MAX as usize);
+            let table = Box::into_raw(Box::new(table));
+            hb_blob_create(
+                (*table).as_ptr() as *const c_char,
+                len as c_uint,
+                HB_MEMORY_MODE_READONLY,
+                table as *mut c_void,
+                Some(table_destroy),
+            )
+        }
+        None => ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn table_destroy(user_data: *mut c_void) {
+    drop(Box::from_raw(user_data as *mut Box<[u8]>));
+}
+
+unsafe extern "C" fn font_destroy(user_data: *mut c_void) {
+    drop(Box::from_raw(user_data as *mut Arc<Font>));
+}
+
 impl Clone for HbFace {
     fn clone(&self) -> HbFace {
         unsafe {
@@ -74,112 +120,101 @@ impl Drop for HbFace {
     }
 }
 
-// TODO: Scheduled for demolition.
-pub fn layout_run(style: &TextStyle, font: &FontRef, text: &str) -> Layout {
-    HB_THREAD_DATA.with(|hb_thread_data| {
-        let mut hb_thread_data = hb_thread_data.borrow_mut();
+/// The HarfBuzz shaping engine.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HarfBuzzShaper;
+
+impl Shaper for HarfBuzzShaper {
+    fn shape(&self, text: &str, params: &ShapeParams) -> ShapedRun {
+        let font = params.font;
         let mut b = Buffer::new();
         install_unicode_funcs(&mut b);
-        b.add_str(text);
-        b.set_direction(Direction::LTR);
-        // TODO: set this based on detected script
-        b.set_script(HB_SCRIPT_DEVANAGARI);
-        b.set_language(Language::from_string("en_US"));
-        let hb_face = hb_thread_data.create_hb_face_for_font(font);
+        // HarfBuzz only looks at a few characters of context each side.
+        let pre_context = context_before(params.pre_context);
+        let post_context = context_after(params.post_context);
+        let buffer_text = [pre_context, text, post_context].concat();
+        unsafe {
+            hb_buffer_add_utf8(
+                b.as_ptr(),
+                buffer_text.as_ptr() as *const c_char,
+                buffer_text.len() as c_int,
+                pre_context.len() as c_uint,
+                text.len() as c_int,
+            );
+        }
+        b.set_direction(hb_direction(params.direction));
+        b.set_script(params.script);
+        if let Some(language) = params.language {
+            b.set_language(Language::from_string(language));
+        }
+        let features: Vec<hb_feature_t> = params
+            .features
+            .iter()
+            .map(|feature| hb_feature_t {
+                tag: feature.tag,
+                value: feature.value,
+                start: 0,
+                end: c_uint::MAX,
+            })
+            .collect();
+        let hb_face = HbFace::get(font);
         unsafe {
             let hb_font = hb_font_create(hb_face.hb_face);
-            hb_shape(hb_font, b.as_ptr(), std::ptr::null(), 0);
+            hb_shape(
+                hb_font,
+                b.as_ptr(),
+                features.as_ptr(),
+                features.len() as c_uint,
+            );
             hb_font_destroy(hb_font);
             let mut n_glyph = 0;
             let glyph_infos = hb_buffer_get_glyph_infos(b.as_ptr(), &mut n_glyph);
-            debug!("number of glyphs: {}", n_glyph);
+            trace!("number of glyphs: {}", n_glyph);
             let glyph_infos = std::slice::from_raw_parts(glyph_infos, n_glyph as usize);
             let mut n_glyph_pos = 0;
             let glyph_positions = hb_buffer_get_glyph_positions(b.as_ptr(), &mut n_glyph_pos);
             let glyph_positions = std::slice::from_raw_parts(glyph_positions, n_glyph_pos as usize);
             let mut total_adv = Vector2F::zero();
             let mut glyphs = Vec::new();
-            let scale = style.size / (font.font.metrics().units_per_em as f32);
+            // TODO: we might want to store this size-invariant.
+            let scale = params.size / (font.font.metrics().units_per_em as f32);
             for (glyph, pos) in glyph_infos.iter().zip(glyph_positions.iter()) {
-                debug!("{:?} {:?}", glyph.codepoint, (pos.x_offset, pos.y_offset));
-                let adv = vec2i(pos.x_advance, pos.y_advance);
+                // HarfBuzz has y growing upward.
+                let adv = vec2i(pos.x_advance, -pos.y_advance);
                 let adv_f = adv.to_f32() * scale;
-                let offset = vec2i(pos.x_offset, pos.y_offset).to_f32() * scale;
-                let g = Glyph {
-                    font: font.clone(),
+                let offset = vec2i(pos.x_offset, -pos.y_offset).to_f32() * scale;
+                let flags = hb_glyph_info_get_glyph_flags(glyph);
+                let unsafe_to_break = flags & HB_GLYPH_FLAG_UNSAFE_TO_BREAK != 0;
+                trace!(
+                    "{:?} {:?} {} {}",
+                    glyph.codepoint, (pos.x_offset, pos.y_offset), glyph.cluster, unsafe_to_break
+                );
+                let g = ShapedGlyph {
+                    // Clusters are offsets into the buffer text.
+                    cluster: glyph.cluster - pre_context.len() as u32,
+                    advance: adv_f,
                     glyph_id: glyph.codepoint,
                     offset: total_adv + offset,
+                    unsafe_to_break,
                 };
                 total_adv += adv_f;
                 glyphs.push(g);
             }
 
-            Layout {
-                size: style.size,
-                glyphs: glyphs,
+            ShapedRun {
+                glyphs,
                 advance: total_adv,
             }
         }
-    })
+    }
 }
 
-pub(crate) fn layout_fragment(
-    style: &TextStyle,
-    font: &FontRef,
-    script: hb_script_t,
-    text: &str,
-) -> LayoutFragment {
-    let mut b = Buffer::new();
-    install_unicode_funcs(&mut b);
-    b.add_str(text);
-    b.set_direction(Direction::LTR);
-    b.set_script(script);
-    b.set_language(Language::from_string("en_US"));
-    let hb_face = HbFace::new(font);
-    unsafe {
-        let hb_font = hb_font_create(hb_face.hb_face);
-        hb_shape(hb_font, b.as_ptr(), std::ptr::null(), 0);
-        hb_font_destroy(hb_font);
-        let mut n_glyph = 0;
-        let glyph_infos = hb_buffer_get_glyph_infos(b.as_ptr(), &mut n_glyph);
-        trace!("number of glyphs: {}", n_glyph);
-        let glyph_infos = std::slice::from_raw_parts(glyph_infos, n_glyph as usize);
-        let mut n_glyph_pos = 0;
-        let glyph_positions = hb_buffer_get_glyph_positions(b.as_ptr(), &mut n_glyph_pos);
-        let glyph_positions = std::slice::from_raw_parts(glyph_positions, n_glyph_pos as usize);
-        let mut total_adv = Vector2F::zero();
-        let mut glyphs = Vec::new();
-        // TODO: we might want to store this size-invariant.
-        let scale = style.size / (font.font.metrics().units_per_em as f32);
-        for (glyph, pos) in glyph_infos.iter().zip(glyph_positions.iter()) {
-            let adv = vec2i(pos.x_advance, pos.y_advance);
-            let adv_f = adv.to_f32() * scale;
-            let offset = vec2i(pos.x_offset, pos.y_offset).to_f32() * scale;
-            let flags = hb_glyph_info_get_glyph_flags(glyph);
-            let unsafe_to_break = flags & HB_GLYPH_FLAG_UNSAFE_TO_BREAK != 0;
-            trace!(
-                "{:?} {:?} {} {}",
-                glyph.codepoint, (pos.x_offset, pos.y_offset), glyph.cluster, unsafe_to_break
-            );
-            let g = FragmentGlyph {
-                cluster: glyph.cluster,
-                advance: adv_f,
-                glyph_id: glyph.codepoint,
-                offset: total_adv + offset,
-                unsafe_to_break,
-            };
-            total_adv += adv_f;
-            glyphs.push(g);
-        }
-
-        LayoutFragment {
-            //size: style.size,
-            substr_len: text.len(),
-            script,
-            glyphs: glyphs,
-            advance: total_adv,
-            font: font.clone(),
-        }
+fn hb_direction(direction: Direction) -> HbDirection {
+    match direction {
+        Direction::LeftToRight => HbDirection::LTR,
+        Direction::RightToLeft => HbDirection::RTL,
+        Direction::TopToBottom => HbDirection::TTB,
+        Direction::BottomToTop => HbDirection::BTT,
     }
 }
 
@@ -193,24 +228,64 @@ fn fixed_to_float(i: hb_position_t) -> f32 {
     (i as f32) * (1.0 / 65536.0)
 }
 
-/*
-struct FontFuncs(*mut hb_font_funcs_t);
+#[cfg(test)]
+mod tests {
+    use harfbuzz::sys::{hb_blob_destroy, hb_blob_get_length, hb_face_reference_blob};
 
-lazy_static! {
-    static ref HB_FONT_FUNCS: FontFuncs = unsafe {
-        let hb_funcs = hb_font_funcs_create();
+    use super::*;
+    use crate::script::SCRIPT_LATIN;
+    use crate::test_util::{font_path, MADA};
+
+    #[test]
+    fn face_is_cached() {
+        let font = FontRef::new(Font::from_path(font_path(MADA), 0).unwrap());
+        let params = ShapeParams {
+            font: &font,
+            size: 16.0,
+            script: SCRIPT_LATIN,
+            direction: Direction::LeftToRight,
+            language: None,
+            features: &[],
+            pre_context: "",
+            post_context: "",
+        };
+        let run = HarfBuzzShaper.shape("abc", &params);
+        assert_eq!(run.glyphs.len(), 3);
+        assert!(run.glyphs.iter().all(|glyph| glyph.glyph_id != 0));
+        let face = HbFace::get(&font).hb_face;
+        assert_eq!(HbFace::get(&font.clone()).hb_face, face);
+        // The loader gave us the file, so HarfBuzz didn't need to ask for
+        // tables one at a time.
+        unsafe {
+            let blob = hb_face_reference_blob(face);
+            assert!(hb_blob_get_length(blob) > 0);
+            hb_blob_destroy(blob);
+        }
     }
-}
-*/
 
-/*
-// Callback to access table data in a font
-unsafe extern "C" fn font_table_func(
-    _: *mut hb_face_t,
-    tag: hb_tag_t,
-    user_data: *mut c_void,
-) -> *mut hb_blob_t {
-    let font = user_data as *const Font;
-    unimplemented!()
+    #[test]
+    fn face_from_tables() {
+        let mut font = FontRef::new(Font::from_path(font_path(MADA), 0).unwrap());
+        font.data = None;
+        let params = ShapeParams {
+            font: &font,
+            size: 16.0,
+            script: SCRIPT_LATIN,
+            direction: Direction::LeftToRight,
+            language: None,
+            features: &[],
+            pre_context: "",
+            post_context: "",
+        };
+        let run = HarfBuzzShaper.shape("abc", &params);
+        assert_eq!(run.glyphs.len(), 3);
+        assert!(run.glyphs.iter().all(|glyph| glyph.glyph_id != 0));
+        // Without the file, the face has no blob of its own, and the tables
+        // came through the callback.
+        unsafe {
+            let blob = hb_face_reference_blob(HbFace::get(&font).hb_face);
+            assert_eq!(hb_blob_get_length(blob), 0);
+            hb_blob_destroy(blob);
+        }
+    }
 }
-*/
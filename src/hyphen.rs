@@ -0,0 +1,65 @@
+//! Hyphens inserted at hyphenation breaks.
+//!
+//! The choice of hyphen follows Minikin: most scripts use U+2010 HYPHEN, with
+//! the script's own hyphen where it has one.
+
+use crate::script::{
+    Script, SCRIPT_ARABIC, SCRIPT_ARMENIAN, SCRIPT_CANADIAN_SYLLABICS, SCRIPT_HEBREW,
+};
+use crate::FontRef;
+
+/// Hyphens to add to a substring, at hyphenation breaks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HyphenEdit {
+    /// Add a hyphen after the text, for the line before a break.
+    pub append: bool,
+    /// Add a hyphen before the text, for the line after a break, in
+    /// languages that repeat the hyphen there (such as Polish and
+    /// Portuguese).
+    pub prepend: bool,
+}
+
+impl HyphenEdit {
+    pub(crate) fn is_empty(self) -> bool {
+        !self.append && !self.prepend
+    }
+}
+
+const ZWJ: char = '\u{200D}';
+
+/// The text to append to a run of the script at a break.
+pub(crate) fn hyphen_after(script: Script, font: &FontRef) -> String {
+    let hyphen = hyphen_char(script, font);
+    if script == SCRIPT_ARABIC {
+        // Keep the letter before the break in its joining form.
+        [ZWJ, hyphen].iter().collect()
+    } else {
+        hyphen.to_string()
+    }
+}
+
+/// The text to prepend to a run of the script after a break.
+pub(crate) fn hyphen_before(script: Script, font: &FontRef) -> String {
+    let hyphen = hyphen_char(script, font);
+    if script == SCRIPT_ARABIC {
+        [hyphen, ZWJ].iter().collect()
+    } else {
+        hyphen.to_string()
+    }
+}
+
+// The script's hyphen, falling back to U+2010 and then to the hyphen-minus
+// if the font doesn't have it.
+fn hyphen_char(script: Script, font: &FontRef) -> char {
+    let preferred = match script {
+        SCRIPT_ARMENIAN => '\u{058A}',
+        SCRIPT_HEBREW => '\u{05BE}',
+        SCRIPT_CANADIAN_SYLLABICS => '\u{1400}',
+        _ => '\u{2010}',
+    };
+    [preferred, '\u{2010}']
+        .iter()
+        .copied()
+        .find(|&c| font.supports_codepoint(c))
+        .unwrap_or('-')
+}
@@ -1,26 +1,143 @@
 #[macro_use]
 extern crate log;
 
-use font_kit::loaders::default::Font;
+use std::ops::Range;
+
 use pathfinder_geometry::vector::Vector2F;
 
+#[cfg(not(any(feature = "harfbuzz", feature = "rustybuzz")))]
+compile_error!("skribo needs a shaper: enable the harfbuzz or the rustybuzz feature");
+#[cfg(not(any(feature = "font-kit", feature = "ttf-parser")))]
+compile_error!("skribo needs a font loader: enable the font-kit or the wasm feature");
+
+#[cfg(feature = "android-fonts")]
+mod android_fonts;
+mod bidi;
+mod builder;
 mod collection;
+mod coverage;
+mod engine;
+mod fallback;
+mod font;
+mod font_data;
+#[cfg(feature = "fontconfig")]
+mod fontconfig_fallback;
+mod generic;
+mod hyphen;
+#[cfg(feature = "harfbuzz")]
 mod hb_layout;
+#[cfg(feature = "line-break")]
+mod linebreak;
+#[cfg(feature = "rustybuzz")]
+mod rb_layout;
+pub mod script;
 mod session;
+mod shaper;
+// The decomposition and mirroring tables are only needed by HarfBuzz.
+#[cfg_attr(not(feature = "harfbuzz"), allow(dead_code))]
 mod tables;
+#[cfg(test)]
+mod test_util;
+#[cfg(not(feature = "font-kit"))]
+mod ttf_font;
+#[cfg(feature = "harfbuzz")]
 mod unicode_funcs;
+mod vertical;
 
-pub use crate::collection::{FontCollection, FontFamily, FontRef};
-pub use crate::hb_layout::layout_run;
-pub use crate::session::LayoutSession;
+#[cfg(feature = "android-fonts")]
+pub use crate::android_fonts::{
+    collection_from_configs, load_android_fonts, parse_fonts_xml, FamilyConfig, FontConfig,
+    FontsXmlError,
+};
+pub use crate::bidi::BaseDirection;
+pub use crate::builder::FontCollectionBuilder;
+pub use crate::collection::{FamilyVariant, FontCollection, FontFamily, FontRef};
+pub use crate::engine::{CacheStats, CachedShaper, Engine};
+#[cfg(feature = "font-kit")]
+pub use crate::fallback::SourceFallback;
+pub use crate::fallback::FallbackProvider;
+#[cfg(feature = "font-kit")]
+use crate::font::Font;
+#[cfg(not(feature = "font-kit"))]
+pub use crate::ttf_font::{
+    FileType, Font, FontLoadingError, GlyphLoadingError, Metrics, Properties, Stretch, Style,
+    Weight,
+};
+pub use crate::font_data::FontData;
+pub use crate::generic::{GenericFamily, GenericFamilyResolver};
+pub use crate::hyphen::HyphenEdit;
+#[cfg(feature = "fontconfig")]
+pub use crate::fontconfig_fallback::FontconfigFallback;
+#[cfg(feature = "harfbuzz")]
+pub use crate::hb_layout::HarfBuzzShaper;
+#[cfg(feature = "line-break")]
+pub use crate::linebreak::{Line, LineBreaker};
+#[cfg(feature = "rustybuzz")]
+pub use crate::rb_layout::RustybuzzShaper;
+pub use crate::session::{LayoutSession, TruncatePosition, Truncation};
+pub use crate::shaper::{Direction, Feature, ShapeParams, ShapedGlyph, ShapedRun, Shaper};
+pub use crate::vertical::Orientation;
 
 #[derive(Clone)]
 pub struct TextStyle {
     // This should be either horiz and vert, or a 2x2 matrix
     pub size: f32,
+    /// BCP-47 locale tags, highest priority first.
+    pub locales: Vec<String>,
+    /// OpenType features applied to all text in the style.
+    pub features: Vec<Feature>,
+    /// The paragraph direction for bidi reordering, or `None` to lay out all
+    /// text left to right, in logical order.
+    pub bidi: Option<BaseDirection>,
+    /// Vertical modes shape with vertical metrics and alternates.
+    pub writing_mode: WritingMode,
+    /// Byte ranges of the text to set horizontally, each in a single upright
+    /// 1 em cell, in vertical writing modes (tate-chu-yoko). This is meant
+    /// for short runs such as two- or three-digit numbers. Each range is
+    /// shaped as one run, with the style and font at its start.
+    pub combine_upright: Vec<Range<usize>>,
+}
+
+impl TextStyle {
+    /// A style of the given size, with no locales or features, laid out
+    /// horizontally and left to right.
+    pub fn new(size: f32) -> TextStyle {
+        TextStyle {
+            size,
+            locales: Vec::new(),
+            features: Vec::new(),
+            bidi: None,
+            writing_mode: WritingMode::HorizontalTb,
+            combine_upright: Vec::new(),
+        }
+    }
+}
+
+impl Default for TextStyle {
+    /// A 16 px style, the CSS default font size.
+    fn default() -> TextStyle {
+        TextStyle::new(16.0)
+    }
+}
+
+/// The direction text flows in, as in the CSS `writing-mode` property.
+///
+/// The vertical modes only differ in which way lines stack, so they lay out a
+/// single line the same way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WritingMode {
+    HorizontalTb,
+    VerticalRl,
+    VerticalLr,
+}
+
+impl WritingMode {
+    pub fn is_vertical(self) -> bool {
+        self != WritingMode::HorizontalTb
+    }
 }
 
-// TODO: remove this (in favor of LayoutSession, which might take over this name)
+/// A laid out string, as returned by `layout`.
 #[derive(Debug)]
 pub struct Layout {
     pub size: f32,
@@ -37,60 +154,10 @@ pub struct Glyph {
     // TODO: more fields for advance, clusters, etc.
 }
 
-impl Layout {
-    pub(crate) fn new() -> Layout {
-        Layout {
-            size: 0.0,
-            glyphs: Vec::new(),
-            advance: Vector2F::default(),
-        }
-    }
-
-    pub(crate) fn push_layout(&mut self, other: &Layout) {
-        self.size = other.size;
-        for glyph in &other.glyphs {
-            self.glyphs.push(Glyph {
-                font: glyph.font.clone(),
-                glyph_id: glyph.glyph_id,
-                offset: self.advance + glyph.offset,
-            });
-        }
-        self.advance += other.advance;
-    }
-}
-
-// This implementation just uses advances and doesn't do fallback.
-pub fn make_layout(style: &TextStyle, font: &FontRef, text: &str) -> Layout {
-    let scale = style.size / (font.font.metrics().units_per_em as f32);
-    let mut pos = Vector2F::default();
-    let mut glyphs = Vec::new();
-    for c in text.chars() {
-        if let Some(glyph_id) = font.font.glyph_for_char(c) {
-            if let Ok(adv) = font.font.advance(glyph_id) {
-                // TODO(font-kit): this doesn't get hinted advance (hdmx) table
-                let adv_f = adv * scale;
-                debug!("{:?}", adv);
-                let glyph = Glyph {
-                    font: font.clone(),
-                    glyph_id,
-                    offset: pos,
-                };
-                glyphs.push(glyph);
-                pos += adv_f;
-            }
-        }
-    }
-    Layout {
-        size: style.size,
-        glyphs,
-        advance: pos,
-    }
-}
-
+/// Lay out a string in one shot.
+///
+/// This runs the same script detection, itemization and shaping as
+/// `LayoutSession`, and returns the glyphs with absolute positions.
 pub fn layout(style: &TextStyle, collection: &FontCollection, text: &str) -> Layout {
-    let mut result = Layout::new();
-    for (range, font) in collection.itemize(text) {
-        result.push_layout(&layout_run(style, font, &text[range]));
-    }
-    result
+    LayoutSession::create(text, style, collection).to_layout()
 }
@@ -0,0 +1,179 @@
+//! Greedy line breaking on top of a layout session.
+//!
+//! Break opportunities come from the Unicode Line Breaking Algorithm (UAX
+//! #14). Candidate lines are measured with `advance_substr`, so widths are
+//! correct when shaping changes at the break.
+
+use std::ops::Range;
+
+use xi_unicode::LineBreakIterator;
+
+use crate::LayoutSession;
+
+/// A line of the paragraph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Line {
+    /// The byte range of the line, including trailing spaces and line
+    /// separators.
+    pub range: Range<usize>,
+    /// The advance of the line along the inline axis, without trailing
+    /// spaces and line separators.
+    pub width: f32,
+}
+
+/// An iterator over the lines of a session, breaking them to fit a maximum
+/// width.
+///
+/// Each line is filled with as much text as fits. A word that doesn't fit on
+/// a line by itself overflows it.
+pub struct LineBreaker<'a, S: AsRef<str>> {
+    session: &'a mut LayoutSession<S>,
+    max_width: f32,
+    // Break opportunities, as the offset and whether the break is mandatory.
+    breaks: Vec<(usize, bool)>,
+    break_ix: usize,
+    line_start: usize,
+}
+
+impl<'a, S: AsRef<str>> LineBreaker<'a, S> {
+    pub fn new(session: &'a mut LayoutSession<S>, max_width: f32) -> LineBreaker<'a, S> {
+        let mut breaks: Vec<(usize, bool)> = LineBreakIterator::new(session.text()).collect();
+        // An empty paragraph is still one line.
+        if breaks.is_empty() {
+            breaks.push((0, true));
+        }
+        LineBreaker {
+            session,
+            max_width,
+            breaks,
+            break_ix: 0,
+            line_start: 0,
+        }
+    }
+
+    // The width of the line from the current start to the break.
+    fn width(&mut self, end: usize) -> f32 {
+        let start = self.line_start;
+        let line = &self.session.text()[start..end];
+        let end = start + line.trim_end_matches(hangs).len();
+        let advance = self.session.advance_substr(start..end);
+        if self.session.writing_mode().is_vertical() {
+            advance.y()
+        } else {
+            advance.x()
+        }
+    }
+}
+
+impl<'a, S: AsRef<str>> Iterator for LineBreaker<'a, S> {
+    type Item = Line;
+
+    fn next(&mut self) -> Option<Line> {
+        let mut line = None;
+        while self.break_ix < self.breaks.len() {
+            let (end, hard) = self.breaks[self.break_ix];
+            let width = self.width(end);
+            let fits = width <= self.max_width;
+            if !fits && line.is_some() {
+                break;
+            }
+            line = Some(Line {
+                range: self.line_start..end,
+                width,
+            });
+            self.break_ix += 1;
+            if hard || !fits {
+                break;
+            }
+        }
+        if let Some(line) = &line {
+            self.line_start = line.range.end;
+        }
+        line
+    }
+}
+
+// Spaces and line separators at the end of a line hang past its edge.
+fn hangs(c: char) -> bool {
+    matches!(
+        c,
+        ' ' | '\t' | '\n' | '\u{b}' | '\u{c}' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{collection, FixedShaper, ADVANCE, MADA};
+    use crate::{BaseDirection, TextStyle};
+
+    enum Width {
+        Zero,
+        Infinite,
+        // The width of this string.
+        Fits(&'static str),
+    }
+
+    // (text, maximum width, expected lines)
+    const CASES: &[(&str, Width, &[&str])] = &[
+        ("hello world", Width::Zero, &["hello ", "world"]),
+        ("hello world", Width::Infinite, &["hello world"]),
+        (
+            "one two three four",
+            Width::Fits("one two three"),
+            &["one two three ", "four"],
+        ),
+        ("aaa   bbb", Width::Fits("aaa"), &["aaa   ", "bbb"]),
+        (
+            "line one\nline two",
+            Width::Infinite,
+            &["line one\n", "line two"],
+        ),
+        ("a\n\nb", Width::Infinite, &["a\n", "\n", "b"]),
+        ("well-known", Width::Zero, &["well-", "known"]),
+        ("a\u{a0}b c", Width::Zero, &["a\u{a0}b ", "c"]),
+        ("(quoted) text.", Width::Zero, &["(quoted) ", "text."]),
+        ("3.14 is pi", Width::Zero, &["3.14 ", "is ", "pi"]),
+        ("日本語。", Width::Zero, &["日", "本", "語。"]),
+        ("سلام عليكم", Width::Zero, &["سلام ", "عليكم"]),
+        ("", Width::Zero, &[""]),
+    ];
+
+    #[test]
+    fn breaks() {
+        let collection = collection(&[MADA]);
+        let style = TextStyle {
+            bidi: Some(BaseDirection::Auto),
+            ..Default::default()
+        };
+        for (text, width, expected) in CASES {
+            let max_width = match width {
+                Width::Zero => 0.0,
+                Width::Infinite => f32::INFINITY,
+                Width::Fits(s) => s.chars().count() as f32 * ADVANCE,
+            };
+            let mut session =
+                LayoutSession::create_with_shaper(*text, &style, &collection, FixedShaper);
+            let lines: Vec<&str> = LineBreaker::new(&mut session, max_width)
+                .map(|line| &text[line.range])
+                .collect();
+            assert_eq!(lines, *expected, "{:?} at {}", text, max_width);
+        }
+    }
+
+    #[test]
+    fn width() {
+        let collection = collection(&[MADA]);
+        let mut session = LayoutSession::create_with_shaper(
+            "one two  three",
+            &TextStyle::default(),
+            &collection,
+            FixedShaper,
+        );
+        let widths: Vec<f32> = LineBreaker::new(&mut session, 7.0 * ADVANCE)
+            .map(|line| line.width)
+            .collect();
+        // Trailing spaces hang, so they don't count.
+        assert_eq!(widths, vec![7.0 * ADVANCE, 5.0 * ADVANCE]);
+    }
+}
@@ -0,0 +1,263 @@
+//! A pure-Rust shaping back-end using rustybuzz.
+
+use pathfinder_geometry::vector::{vec2i, Vector2F};
+
+use rustybuzz::ttf_parser::Tag;
+use rustybuzz::{Face, Language, Script, UnicodeBuffer};
+
+use crate::font::copy_data;
+use crate::shaper::{Direction, ShapeParams, ShapedGlyph, ShapedRun, Shaper};
+use crate::FontRef;
+
+/// The rustybuzz shaping engine.
+///
+/// This is a port of HarfBuzz to Rust, and should give the same results as
+/// `HarfBuzzShaper`, without linking any C code.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RustybuzzShaper;
+
+impl Shaper for RustybuzzShaper {
+    fn shape(&self, text: &str, params: &ShapeParams) -> ShapedRun {
+        let font = params.font;
+        let (data, index) = match font_bytes(font) {
+            Some(data) => data,
+            None => {
+                warn!("no font data for {:?}", font);
+                return ShapedRun::default();
+            }
+        };
+        let face = match Face::from_slice(data, index) {
+            Some(face) => face,
+            None => {
+                warn!("rustybuzz couldn't parse {:?}", font);
+                return ShapedRun::default();
+            }
+        };
+        let mut b = UnicodeBuffer::new();
+        b.push_str(text);
+        b.set_pre_context(params.pre_context);
+        b.set_post_context(params.post_context);
+        b.set_direction(rb_direction(params.direction));
+        if let Some(script) = Script::from_iso15924_tag(Tag(params.script)) {
+            b.set_script(script);
+        }
+        if let Some(language) = params.language.and_then(|l| l.parse::<Language>().ok()) {
+            b.set_language(language);
+        }
+        let features: Vec<rustybuzz::Feature> = params
+            .features
+            .iter()
+            .map(|feature| rustybuzz::Feature::new(Tag(feature.tag), feature.value, ..))
+            .collect();
+        let output = rustybuzz::shape(&face, &features, b);
+        let mut total_adv = Vector2F::zero();
+        let mut glyphs = Vec::new();
+        let scale = params.size / (font.font.metrics().units_per_em as f32);
+        for (glyph, pos) in output.glyph_infos().iter().zip(output.glyph_positions()) {
+            // rustybuzz has y growing upward, like HarfBuzz.
+            let adv = vec2i(pos.x_advance, -pos.y_advance);
+            let adv_f = adv.to_f32() * scale;
+            let offset = vec2i(pos.x_offset, -pos.y_offset).to_f32() * scale;
+            let g = ShapedGlyph {
+                cluster: glyph.cluster,
+                advance: adv_f,
+                glyph_id: glyph.glyph_id,
+                offset: total_adv + offset,
+                unsafe_to_break: glyph.unsafe_to_break(),
+            };
+            total_adv += adv_f;
+            glyphs.push(g);
+        }
+        ShapedRun {
+            glyphs,
+            advance: total_adv,
+        }
+    }
+}
+
+// rustybuzz parses the font file itself, so unlike HarfBuzz we can't fetch
+// tables one at a time through the loader. If the loader can only give us a
+// copy of the file, we have to find the face in it, as font-kit doesn't tell
+// us which one it loaded. The copy is cached in the `FontRef`.
+fn font_bytes(font: &FontRef) -> Option<(&[u8], u32)> {
+    let (data, index) = match font.data() {
+        Some(data) => data,
+        None => {
+            let (data, index) = font
+                .copied_data
+                .get_or_init(|| copy_data(&font.font))
+                .as_ref()?;
+            (data, *index)
+        }
+    };
+    Some((data.as_bytes(), index))
+}
+
+fn rb_direction(direction: Direction) -> rustybuzz::Direction {
+    match direction {
+        Direction::LeftToRight => rustybuzz::Direction::LeftToRight,
+        Direction::RightToLeft => rustybuzz::Direction::RightToLeft,
+        Direction::TopToBottom => rustybuzz::Direction::TopToBottom,
+        Direction::BottomToTop => rustybuzz::Direction::BottomToTop,
+    }
+}
+
+// Differential test against HarfBuzz, with the bundled fonts. Runs with
+// missing glyphs are skipped, since the engines are allowed to position
+// .notdef differently.
+#[cfg(all(test, feature = "harfbuzz"))]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::test_util::{font_bytes, make_collection, ESTEDAD, MADA};
+    use crate::{Font, FontCollectionBuilder, HarfBuzzShaper};
+
+    // (ISO 15924 script tag, direction, language, text, pre- and post-context)
+    type Case = (
+        &'static [u8; 4],
+        Direction,
+        Option<&'static str>,
+        &'static str,
+        &'static str,
+        &'static str,
+    );
+
+    const CORPUS: &[Case] = &[
+        (
+            b"Latn",
+            Direction::LeftToRight,
+            Some("en"),
+            "office affine AVATAR Tr",
+            "",
+            "",
+        ),
+        (
+            b"Latn",
+            Direction::LeftToRight,
+            None,
+            "e\u{301} a\u{30A}\u{323} n\u{303}",
+            "",
+            "",
+        ),
+        (
+            b"Latn",
+            Direction::LeftToRight,
+            Some("fr"),
+            "« Ça va ? » — oui",
+            "",
+            "",
+        ),
+        (
+            b"Arab",
+            Direction::RightToLeft,
+            Some("ar"),
+            "مرحبا بالعالم",
+            "",
+            "",
+        ),
+        (
+            b"Arab",
+            Direction::RightToLeft,
+            Some("fa"),
+            "سلام دنیا ۱۲۳",
+            "",
+            "",
+        ),
+        (b"Arab", Direction::RightToLeft, Some("ur"), "ہے۔", "", ""),
+        // Joining across the ends of the run.
+        (b"Arab", Direction::RightToLeft, Some("ar"), "لعا", "ب", "م"),
+        (b"Zyyy", Direction::LeftToRight, None, "1/2 - 3 + 4", "", ""),
+    ];
+
+    fn params<'a>(font: &'a FontRef, case: &Case) -> ShapeParams<'a> {
+        let &(script, direction, language, _, pre_context, post_context) = case;
+        ShapeParams {
+            font,
+            size: 16.0,
+            script: u32::from_be_bytes(*script),
+            direction,
+            language,
+            features: &[],
+            pre_context,
+            post_context,
+        }
+    }
+
+    fn compare(hb: &ShapedRun, rb: &ShapedRun) -> Result<(), String> {
+        if hb.glyphs.len() != rb.glyphs.len() {
+            return Err(format!("{} glyphs vs {}", hb.glyphs.len(), rb.glyphs.len()));
+        }
+        for (i, (a, b)) in hb.glyphs.iter().zip(&rb.glyphs).enumerate() {
+            let same = a.glyph_id == b.glyph_id
+                && a.cluster == b.cluster
+                && a.unsafe_to_break == b.unsafe_to_break
+                && (a.advance - b.advance).length() < 1e-3
+                && (a.offset - b.offset).length() < 1e-3;
+            if !same {
+                return Err(format!("glyph {}: {:?} vs {:?}", i, a, b));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn same_as_harfbuzz() {
+        let mut n_runs = 0;
+        for name in &[MADA, ESTEDAD] {
+            let mut builder = FontCollectionBuilder::new();
+            builder.add_bytes(Arc::new(font_bytes(name)), 0).unwrap();
+            let collection = builder.build();
+            let font = collection.families[0].default_font().unwrap();
+            for case in CORPUS {
+                let params = params(font, case);
+                let text = case.3;
+                let hb = HarfBuzzShaper.shape(text, &params);
+                if hb.glyphs.iter().any(|g| g.glyph_id == 0) {
+                    continue;
+                }
+                let rb = RustybuzzShaper.shape(text, &params);
+                if let Err(diff) = compare(&hb, &rb) {
+                    panic!("{:?} in {}: {}", text, name, diff);
+                }
+                n_runs += 1;
+            }
+        }
+        assert!(n_runs >= 12, "only {} runs compared", n_runs);
+    }
+
+    #[test]
+    fn collection_face_index() {
+        let estedad = font_bytes(ESTEDAD);
+        let collection = make_collection(&[font_bytes(MADA), estedad.clone()]);
+        let font = FontRef::new(Font::from_bytes(Arc::new(collection), 1).unwrap());
+        let alone = FontRef::new(Font::from_bytes(Arc::new(estedad), 0).unwrap());
+        let case = &CORPUS[3];
+        let expected = HarfBuzzShaper.shape(case.3, &params(&alone, case));
+        for run in &[
+            HarfBuzzShaper.shape(case.3, &params(&font, case)),
+            RustybuzzShaper.shape(case.3, &params(&font, case)),
+        ] {
+            compare(&expected, run).unwrap();
+        }
+    }
+
+    #[test]
+    fn copied_data_is_cached() {
+        let mut font = FontRef::new(Font::from_bytes(Arc::new(font_bytes(MADA)), 0).unwrap());
+        font.data = None;
+        let case = &CORPUS[0];
+        let copied = |font: &FontRef| {
+            let (data, index) = font.copied_data.get().unwrap().as_ref().unwrap();
+            (data.as_bytes().as_ptr(), *index)
+        };
+        let first = RustybuzzShaper.shape(case.3, &params(&font, case));
+        assert!(first.glyphs.iter().all(|glyph| glyph.glyph_id != 0));
+        let (bytes, index) = copied(&font);
+        assert_eq!(index, 0);
+        let clone = font.clone();
+        let again = RustybuzzShaper.shape(case.3, &params(&clone, case));
+        compare(&first, &again).unwrap();
+        assert_eq!(copied(&clone), (bytes, index));
+    }
+}
@@ -0,0 +1,219 @@
+//! Script identifiers.
+//!
+//! Scripts are identified by their ISO 15924 tag, packed big-endian into a
+//! `u32`. These are the same values HarfBuzz uses for `hb_script_t`, so they
+//! can be passed to HarfBuzz directly, but we don't depend on HarfBuzz for
+//! them.
+
+use std::cmp::Ordering;
+
+use crate::tables::{SCRIPT_EXT_KEY, SCRIPT_EXT_VAL, SCRIPT_KEY, SCRIPT_VAL};
+
+/// An ISO 15924 script tag.
+pub type Script = u32;
+
+/// The script with the given ISO 15924 tag, for example `b"Latn"`.
+pub const fn script_from_tag(tag: &[u8; 4]) -> Script {
+    u32::from_be_bytes(*tag)
+}
+
+/// Lookup the script property of a Codepoint.
+///
+/// The `Script` type is a big-endian encoding of the 4-byte string; this can also
+/// be used for other purposes such as script matching during itemization.
+///
+/// Note that for unknown script, the unknown script value is returned ("Zzzz").
+pub fn lookup_script(query: u32) -> Script {
+    if let Ok(ix) = SCRIPT_KEY.binary_search_by(|&range| cmp_range(range, query)) {
+        SCRIPT_VAL[ix]
+    } else {
+        SCRIPT_UNKNOWN
+    }
+}
+
+/// Lookup the Script_Extensions property of a codepoint.
+///
+/// This is the set of scripts the character is used with. For most
+/// characters it is just the script property, but some punctuation and
+/// marks are shared by a few scripts; for example the Devanagari danda
+/// (U+0964) is also used in Bengali and other Indic scripts.
+pub fn lookup_script_extensions(query: u32) -> &'static [Script] {
+    if let Ok(ix) = SCRIPT_EXT_KEY.binary_search_by(|&range| cmp_range(range, query)) {
+        SCRIPT_EXT_VAL[ix]
+    } else if let Ok(ix) = SCRIPT_KEY.binary_search_by(|&range| cmp_range(range, query)) {
+        std::slice::from_ref(&SCRIPT_VAL[ix])
+    } else {
+        &[SCRIPT_UNKNOWN]
+    }
+}
+
+// The generated ranges have exclusive ends.
+pub(crate) fn cmp_range((s, e): (u32, u32), query: u32) -> Ordering {
+    if s > query {
+        Ordering::Greater
+    } else if e <= query {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+// The list follows harfbuzz-sys 0.5.
+
+pub const SCRIPT_COMMON: Script = script_from_tag(b"Zyyy");
+pub const SCRIPT_INHERITED: Script = script_from_tag(b"Zinh");
+pub const SCRIPT_UNKNOWN: Script = script_from_tag(b"Zzzz");
+pub const SCRIPT_ARABIC: Script = script_from_tag(b"Arab");
+pub const SCRIPT_ARMENIAN: Script = script_from_tag(b"Armn");
+pub const SCRIPT_BENGALI: Script = script_from_tag(b"Beng");
+pub const SCRIPT_CYRILLIC: Script = script_from_tag(b"Cyrl");
+pub const SCRIPT_DEVANAGARI: Script = script_from_tag(b"Deva");
+pub const SCRIPT_GEORGIAN: Script = script_from_tag(b"Geor");
+pub const SCRIPT_GREEK: Script = script_from_tag(b"Grek");
+pub const SCRIPT_GUJARATI: Script = script_from_tag(b"Gujr");
+pub const SCRIPT_GURMUKHI: Script = script_from_tag(b"Guru");
+pub const SCRIPT_HANGUL: Script = script_from_tag(b"Hang");
+pub const SCRIPT_HAN: Script = script_from_tag(b"Hani");
+pub const SCRIPT_HEBREW: Script = script_from_tag(b"Hebr");
+pub const SCRIPT_HIRAGANA: Script = script_from_tag(b"Hira");
+pub const SCRIPT_KANNADA: Script = script_from_tag(b"Knda");
+pub const SCRIPT_KATAKANA: Script = script_from_tag(b"Kana");
+pub const SCRIPT_LAO: Script = script_from_tag(b"Laoo");
+pub const SCRIPT_LATIN: Script = script_from_tag(b"Latn");
+pub const SCRIPT_MALAYALAM: Script = script_from_tag(b"Mlym");
+pub const SCRIPT_ORIYA: Script = script_from_tag(b"Orya");
+pub const SCRIPT_TAMIL: Script = script_from_tag(b"Taml");
+pub const SCRIPT_TELUGU: Script = script_from_tag(b"Telu");
+pub const SCRIPT_THAI: Script = script_from_tag(b"Thai");
+pub const SCRIPT_TIBETAN: Script = script_from_tag(b"Tibt");
+pub const SCRIPT_BOPOMOFO: Script = script_from_tag(b"Bopo");
+pub const SCRIPT_BRAILLE: Script = script_from_tag(b"Brai");
+pub const SCRIPT_CANADIAN_SYLLABICS: Script = script_from_tag(b"Cans");
+pub const SCRIPT_CHEROKEE: Script = script_from_tag(b"Cher");
+pub const SCRIPT_ETHIOPIC: Script = script_from_tag(b"Ethi");
+pub const SCRIPT_KHMER: Script = script_from_tag(b"Khmr");
+pub const SCRIPT_MONGOLIAN: Script = script_from_tag(b"Mong");
+pub const SCRIPT_MYANMAR: Script = script_from_tag(b"Mymr");
+pub const SCRIPT_OGHAM: Script = script_from_tag(b"Ogam");
+pub const SCRIPT_RUNIC: Script = script_from_tag(b"Runr");
+pub const SCRIPT_SINHALA: Script = script_from_tag(b"Sinh");
+pub const SCRIPT_SYRIAC: Script = script_from_tag(b"Syrc");
+pub const SCRIPT_THAANA: Script = script_from_tag(b"Thaa");
+pub const SCRIPT_YI: Script = script_from_tag(b"Yiii");
+pub const SCRIPT_DESERET: Script = script_from_tag(b"Dsrt");
+pub const SCRIPT_GOTHIC: Script = script_from_tag(b"Goth");
+pub const SCRIPT_OLD_ITALIC: Script = script_from_tag(b"Ital");
+pub const SCRIPT_BUHID: Script = script_from_tag(b"Buhd");
+pub const SCRIPT_HANUNOO: Script = script_from_tag(b"Hano");
+pub const SCRIPT_TAGALOG: Script = script_from_tag(b"Tglg");
+pub const SCRIPT_TAGBANWA: Script = script_from_tag(b"Tagb");
+pub const SCRIPT_CYPRIOT: Script = script_from_tag(b"Cprt");
+pub const SCRIPT_LIMBU: Script = script_from_tag(b"Limb");
+pub const SCRIPT_LINEAR_B: Script = script_from_tag(b"Linb");
+pub const SCRIPT_OSMANYA: Script = script_from_tag(b"Osma");
+pub const SCRIPT_SHAVIAN: Script = script_from_tag(b"Shaw");
+pub const SCRIPT_TAI_LE: Script = script_from_tag(b"Tale");
+pub const SCRIPT_UGARITIC: Script = script_from_tag(b"Ugar");
+pub const SCRIPT_BUGINESE: Script = script_from_tag(b"Bugi");
+pub const SCRIPT_COPTIC: Script = script_from_tag(b"Copt");
+pub const SCRIPT_GLAGOLITIC: Script = script_from_tag(b"Glag");
+pub const SCRIPT_KHAROSHTHI: Script = script_from_tag(b"Khar");
+pub const SCRIPT_NEW_TAI_LUE: Script = script_from_tag(b"Talu");
+pub const SCRIPT_OLD_PERSIAN: Script = script_from_tag(b"Xpeo");
+pub const SCRIPT_SYLOTI_NAGRI: Script = script_from_tag(b"Sylo");
+pub const SCRIPT_TIFINAGH: Script = script_from_tag(b"Tfng");
+pub const SCRIPT_BALINESE: Script = script_from_tag(b"Bali");
+pub const SCRIPT_CUNEIFORM: Script = script_from_tag(b"Xsux");
+pub const SCRIPT_NKO: Script = script_from_tag(b"Nkoo");
+pub const SCRIPT_PHAGS_PA: Script = script_from_tag(b"Phag");
+pub const SCRIPT_PHOENICIAN: Script = script_from_tag(b"Phnx");
+pub const SCRIPT_CARIAN: Script = script_from_tag(b"Cari");
+pub const SCRIPT_CHAM: Script = script_from_tag(b"Cham");
+pub const SCRIPT_KAYAH_LI: Script = script_from_tag(b"Kali");
+pub const SCRIPT_LEPCHA: Script = script_from_tag(b"Lepc");
+pub const SCRIPT_LYCIAN: Script = script_from_tag(b"Lyci");
+pub const SCRIPT_LYDIAN: Script = script_from_tag(b"Lydi");
+pub const SCRIPT_OL_CHIKI: Script = script_from_tag(b"Olck");
+pub const SCRIPT_REJANG: Script = script_from_tag(b"Rjng");
+pub const SCRIPT_SAURASHTRA: Script = script_from_tag(b"Saur");
+pub const SCRIPT_SUNDANESE: Script = script_from_tag(b"Sund");
+pub const SCRIPT_VAI: Script = script_from_tag(b"Vaii");
+pub const SCRIPT_AVESTAN: Script = script_from_tag(b"Avst");
+pub const SCRIPT_BAMUM: Script = script_from_tag(b"Bamu");
+pub const SCRIPT_EGYPTIAN_HIEROGLYPHS: Script = script_from_tag(b"Egyp");
+pub const SCRIPT_IMPERIAL_ARAMAIC: Script = script_from_tag(b"Armi");
+pub const SCRIPT_INSCRIPTIONAL_PAHLAVI: Script = script_from_tag(b"Phli");
+pub const SCRIPT_INSCRIPTIONAL_PARTHIAN: Script = script_from_tag(b"Prti");
+pub const SCRIPT_JAVANESE: Script = script_from_tag(b"Java");
+pub const SCRIPT_KAITHI: Script = script_from_tag(b"Kthi");
+pub const SCRIPT_LISU: Script = script_from_tag(b"Lisu");
+pub const SCRIPT_MEETEI_MAYEK: Script = script_from_tag(b"Mtei");
+pub const SCRIPT_OLD_SOUTH_ARABIAN: Script = script_from_tag(b"Sarb");
+pub const SCRIPT_OLD_TURKIC: Script = script_from_tag(b"Orkh");
+pub const SCRIPT_SAMARITAN: Script = script_from_tag(b"Samr");
+pub const SCRIPT_TAI_THAM: Script = script_from_tag(b"Lana");
+pub const SCRIPT_TAI_VIET: Script = script_from_tag(b"Tavt");
+pub const SCRIPT_BATAK: Script = script_from_tag(b"Batk");
+pub const SCRIPT_BRAHMI: Script = script_from_tag(b"Brah");
+pub const SCRIPT_MANDAIC: Script = script_from_tag(b"Mand");
+pub const SCRIPT_CHAKMA: Script = script_from_tag(b"Cakm");
+pub const SCRIPT_MEROITIC_CURSIVE: Script = script_from_tag(b"Merc");
+pub const SCRIPT_MEROITIC_HIEROGLYPHS: Script = script_from_tag(b"Mero");
+pub const SCRIPT_MIAO: Script = script_from_tag(b"Plrd");
+pub const SCRIPT_SHARADA: Script = script_from_tag(b"Shrd");
+pub const SCRIPT_SORA_SOMPENG: Script = script_from_tag(b"Sora");
+pub const SCRIPT_TAKRI: Script = script_from_tag(b"Takr");
+pub const SCRIPT_BASSA_VAH: Script = script_from_tag(b"Bass");
+pub const SCRIPT_CAUCASIAN_ALBANIAN: Script = script_from_tag(b"Aghb");
+pub const SCRIPT_DUPLOYAN: Script = script_from_tag(b"Dupl");
+pub const SCRIPT_ELBASAN: Script = script_from_tag(b"Elba");
+pub const SCRIPT_GRANTHA: Script = script_from_tag(b"Gran");
+pub const SCRIPT_KHOJKI: Script = script_from_tag(b"Khoj");
+pub const SCRIPT_KHUDAWADI: Script = script_from_tag(b"Sind");
+pub const SCRIPT_LINEAR_A: Script = script_from_tag(b"Lina");
+pub const SCRIPT_MAHAJANI: Script = script_from_tag(b"Mahj");
+pub const SCRIPT_MANICHAEAN: Script = script_from_tag(b"Mani");
+pub const SCRIPT_MENDE_KIKAKUI: Script = script_from_tag(b"Mend");
+pub const SCRIPT_MODI: Script = script_from_tag(b"Modi");
+pub const SCRIPT_MRO: Script = script_from_tag(b"Mroo");
+pub const SCRIPT_NABATAEAN: Script = script_from_tag(b"Nbat");
+pub const SCRIPT_OLD_NORTH_ARABIAN: Script = script_from_tag(b"Narb");
+pub const SCRIPT_OLD_PERMIC: Script = script_from_tag(b"Perm");
+pub const SCRIPT_PAHAWH_HMONG: Script = script_from_tag(b"Hmng");
+pub const SCRIPT_PALMYRENE: Script = script_from_tag(b"Palm");
+pub const SCRIPT_PAU_CIN_HAU: Script = script_from_tag(b"Pauc");
+pub const SCRIPT_PSALTER_PAHLAVI: Script = script_from_tag(b"Phlp");
+pub const SCRIPT_SIDDHAM: Script = script_from_tag(b"Sidd");
+pub const SCRIPT_TIRHUTA: Script = script_from_tag(b"Tirh");
+pub const SCRIPT_WARANG_CITI: Script = script_from_tag(b"Wara");
+pub const SCRIPT_AHOM: Script = script_from_tag(b"Ahom");
+pub const SCRIPT_ANATOLIAN_HIEROGLYPHS: Script = script_from_tag(b"Hluw");
+pub const SCRIPT_HATRAN: Script = script_from_tag(b"Hatr");
+pub const SCRIPT_MULTANI: Script = script_from_tag(b"Mult");
+pub const SCRIPT_OLD_HUNGARIAN: Script = script_from_tag(b"Hung");
+pub const SCRIPT_SIGNWRITING: Script = script_from_tag(b"Sgnw");
+pub const SCRIPT_ADLAM: Script = script_from_tag(b"Adlm");
+pub const SCRIPT_BHAIKSUKI: Script = script_from_tag(b"Bhks");
+pub const SCRIPT_MARCHEN: Script = script_from_tag(b"Marc");
+pub const SCRIPT_OSAGE: Script = script_from_tag(b"Osge");
+pub const SCRIPT_TANGUT: Script = script_from_tag(b"Tang");
+pub const SCRIPT_NEWA: Script = script_from_tag(b"Newa");
+pub const SCRIPT_MASARAM_GONDI: Script = script_from_tag(b"Gonm");
+pub const SCRIPT_NUSHU: Script = script_from_tag(b"Nshu");
+pub const SCRIPT_SOYOMBO: Script = script_from_tag(b"Soyo");
+pub const SCRIPT_ZANABAZAR_SQUARE: Script = script_from_tag(b"Zanb");
+pub const SCRIPT_DOGRA: Script = script_from_tag(b"Dogr");
+pub const SCRIPT_GUNJALA_GONDI: Script = script_from_tag(b"Gong");
+pub const SCRIPT_HANIFI_ROHINGYA: Script = script_from_tag(b"Rohg");
+pub const SCRIPT_MAKASAR: Script = script_from_tag(b"Maka");
+pub const SCRIPT_MEDEFAIDRIN: Script = script_from_tag(b"Medf");
+pub const SCRIPT_OLD_SOGDIAN: Script = script_from_tag(b"Sogo");
+pub const SCRIPT_SOGDIAN: Script = script_from_tag(b"Sogd");
+pub const SCRIPT_ELYMAIC: Script = script_from_tag(b"Elym");
+pub const SCRIPT_NANDINAGARI: Script = script_from_tag(b"Nand");
+pub const SCRIPT_NYIAKENG_PUACHUE_HMONG: Script = script_from_tag(b"Hmnp");
+pub const SCRIPT_WANCHO: Script = script_from_tag(b"Wcho");
+pub const SCRIPT_CHORASMIAN: Script = script_from_tag(b"Chrs");
+pub const SCRIPT_DIVES_AKURU: Script = script_from_tag(b"Diak");
+pub const SCRIPT_KHITAN_SMALL_SCRIPT: Script = script_from_tag(b"Kits");
+pub const SCRIPT_YEZIDI: Script = script_from_tag(b"Yezi");
@@ -1,55 +1,195 @@
 //! Retained layout that supports substring queries.
 
+use std::cmp::Ordering;
 use std::ops::Range;
 
-use harfbuzz::sys::{hb_script_t, HB_SCRIPT_COMMON, HB_SCRIPT_INHERITED, HB_SCRIPT_UNKNOWN};
+use pathfinder_geometry::vector::{vec2f, Vector2F};
+use unicode_segmentation::UnicodeSegmentation;
 
-use pathfinder_geometry::vector::Vector2F;
-
-use crate::hb_layout::layout_fragment;
-use crate::unicode_funcs::lookup_script;
-use crate::{FontCollection, FontRef, TextStyle};
+use crate::bidi::{bidi_paragraphs, visual_order, BaseDirection};
+use crate::engine::Engine;
+use crate::fallback::locale_for_script;
+use crate::hyphen::{hyphen_after, hyphen_before, HyphenEdit};
+use crate::script::{
+    lookup_script, lookup_script_extensions, Script, SCRIPT_COMMON, SCRIPT_INHERITED,
+};
+use crate::shaper::{Direction, Feature, ShapeParams, ShapedGlyph, Shaper};
+use crate::tables::{PAIRED_BRACKET_KEY, PAIRED_BRACKET_VAL};
+use crate::vertical::{vertical_runs, Orientation, VerticalRun};
+use crate::{FontCollection, FontRef, Glyph, Layout, TextStyle, WritingMode};
 
 pub struct LayoutSession<S: AsRef<str>> {
     text: S,
-    style: TextStyle,
+    // The base style, then one for each span.
+    styles: Vec<TextStyle>,
+    shaper: Box<dyn Shaper>,
+    // In logical order.
     fragments: Vec<LayoutFragment>,
+    // Indices into `fragments`, in visual order.
+    order: Vec<usize>,
 
     // A separate layout for the substring if needed.
     substr_fragments: Vec<LayoutFragment>,
+    substr_order: Vec<usize>,
 }
 
 pub(crate) struct LayoutFragment {
     // Length of substring covered by this fragment.
     pub(crate) substr_len: usize,
-    pub(crate) script: hb_script_t,
+    pub(crate) props: ItemProps,
+    pub(crate) direction: Direction,
+    // Horizontal compression of combined upright runs that don't fit.
+    pub(crate) horizontal_scale: f32,
     pub(crate) advance: Vector2F,
-    pub(crate) glyphs: Vec<FragmentGlyph>,
+    pub(crate) glyphs: Vec<ShapedGlyph>,
     pub(crate) font: FontRef,
 }
 
-// This should probably be renamed "glyph".
-//
-// Discussion topic: this is so similar to hb_glyph_info_t, maybe we
-// should just use that.
-pub(crate) struct FragmentGlyph {
-    pub cluster: u32,
-    pub glyph_id: u32,
-    pub offset: Vector2F,
-    pub advance: Vector2F,
-    pub unsafe_to_break: bool,
+/// What a fragment was itemized by, other than its font.
+#[derive(Clone, Copy)]
+pub(crate) struct ItemProps {
+    pub(crate) script: Script,
+    // Bidi embedding level; odd levels are right to left.
+    pub(crate) level: u8,
+    // Index of the bidi paragraph; runs are only reordered within one.
+    pub(crate) paragraph: usize,
+    pub(crate) orientation: Orientation,
+    pub(crate) combine_upright: bool,
+    // Index into the session's styles.
+    pub(crate) style: usize,
+}
+
+impl LayoutFragment {
+    fn shape(
+        shaper: &dyn Shaper,
+        styles: &[TextStyle],
+        font: &FontRef,
+        props: ItemProps,
+        text: &str,
+        pre_context: &str,
+        post_context: &str,
+    ) -> LayoutFragment {
+        let style = &styles[props.style];
+        // The writing mode is for the whole paragraph.
+        let direction = fragment_direction(styles[0].writing_mode, props);
+        // Features we add go before the style's, so that those can turn them
+        // off.
+        let added_features = if direction.is_vertical() {
+            // Vertical alternates.
+            vec![Feature::new(b"vert", 1), Feature::new(b"vrt2", 1)]
+        } else if props.combine_upright {
+            // Half, third or quarter width forms, to fit in one em.
+            match text.chars().count() {
+                0 | 1 => vec![],
+                2 => vec![Feature::new(b"hwid", 1)],
+                3 => vec![Feature::new(b"twid", 1)],
+                _ => vec![Feature::new(b"qwid", 1)],
+            }
+        } else {
+            vec![]
+        };
+        let all_features;
+        let features = if added_features.is_empty() {
+            &style.features
+        } else {
+            all_features = [&added_features[..], &style.features].concat();
+            &all_features
+        };
+        let params = ShapeParams {
+            font,
+            size: style.size,
+            script: props.script,
+            direction,
+            language: locale_for_script(&style.locales, props.script),
+            features,
+            pre_context,
+            post_context,
+        };
+        let mut run = shaper.shape(text, &params);
+        let mut horizontal_scale = 1.0;
+        if props.combine_upright {
+            // Set the run horizontally in a 1 em cell, centred on the line,
+            // and compress it if the width forms didn't make it fit.
+            let metrics = font.font.metrics();
+            let em = style.size;
+            let width = run.advance.x();
+            if width > em {
+                horizontal_scale = em / width;
+            }
+            let baseline = em * metrics.ascent / (metrics.ascent - metrics.descent);
+            let origin = vec2f(-width * horizontal_scale / 2.0, baseline);
+            for glyph in &mut run.glyphs {
+                glyph.offset =
+                    origin + vec2f(glyph.offset.x() * horizontal_scale, glyph.offset.y());
+                glyph.advance = vec2f(glyph.advance.x() * horizontal_scale, glyph.advance.y());
+            }
+            run.advance = vec2f(0.0, em);
+        } else if props.orientation == Orientation::Sideways {
+            // Turn the horizontal run 90° clockwise into the line, with the
+            // em box centred on it like upright glyphs.
+            let metrics = font.font.metrics();
+            let scale = style.size / (metrics.units_per_em as f32);
+            let shift = (metrics.ascent + metrics.descent) * scale / 2.0;
+            for glyph in &mut run.glyphs {
+                glyph.offset = vec2f(-glyph.offset.y() - shift, glyph.offset.x());
+                glyph.advance = vec2f(-glyph.advance.y(), glyph.advance.x());
+            }
+            run.advance = vec2f(-run.advance.y(), run.advance.x());
+        }
+        LayoutFragment {
+            substr_len: text.len(),
+            props,
+            direction,
+            horizontal_scale,
+            advance: run.advance,
+            glyphs: run.glyphs,
+            font: font.clone(),
+        }
+    }
 }
 
 pub struct LayoutRangeIter<'a> {
+    styles: &'a [TextStyle],
     fragments: &'a [LayoutFragment],
+    order: &'a [usize],
     offset: Vector2F,
-    fragment_ix: usize,
+    order_ix: usize,
 }
 
 pub struct LayoutRun<'a> {
     // This should potentially be in fragment (would make it easier to binary search)
     offset: Vector2F,
     fragment: &'a LayoutFragment,
+    style: &'a TextStyle,
+}
+
+/// Where `LayoutSession::truncate` removes text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TruncatePosition {
+    Start,
+    Middle,
+    End,
+}
+
+/// A truncated layout, as returned by `LayoutSession::truncate`.
+pub struct Truncation<'a> {
+    /// The runs of the layout, including the ellipsis.
+    pub runs: LayoutRangeIter<'a>,
+    /// The byte ranges of the text that are kept, in logical order.
+    pub ranges: Vec<Range<usize>>,
+}
+
+// What a substring is laid out next to, at one of its ends.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SubstrEdge {
+    // The rest of the text, as context.
+    Text,
+    // Nothing; the text there isn't shown.
+    Cut,
+    // A hyphen, shaped with the substring.
+    Hyphen,
+    // An ellipsis, shaped with the substring.
+    Ellipsis,
 }
 
 pub struct RunIter<'a> {
@@ -64,52 +204,272 @@ pub struct GlyphInfo {
 }
 
 impl<S: AsRef<str>> LayoutSession<S> {
+    /// Create a session, shaping through the global engine's cache.
     pub fn create(
         text: S,
         style: &TextStyle,
         collection: &FontCollection,
     ) -> LayoutSession<S> {
-        let mut i = 0;
-        let mut fragments = Vec::new();
-        while i < text.as_ref().len() {
-            let (script, script_len) = get_script_run(&text.as_ref()[i..]);
-            let script_substr = &text.as_ref()[i..i + script_len];
-            for (range, font) in collection.itemize(script_substr) {
-                let fragment = layout_fragment(style, font, script, &script_substr[range]);
-                fragments.push(fragment);
-            }
-            i += script_len;
-        }
-        let substr_fragments = Vec::new();
+        LayoutSession::create_with_shaper(text, style, collection, Engine::global().shaper())
+    }
+
+    /// Create a session that uses the given shaping engine.
+    pub fn create_with_shaper(
+        text: S,
+        style: &TextStyle,
+        collection: &FontCollection,
+        shaper: impl Shaper + 'static,
+    ) -> LayoutSession<S> {
+        LayoutSession::create_rich_with_shaper(text, style, &[], collection, shaper)
+    }
+
+    /// Create a session for rich text, with styles for spans of the text.
+    ///
+    /// Text outside the spans has the base style, and where spans overlap
+    /// the later one wins. Properties of the whole paragraph (`bidi`,
+    /// `writing_mode` and `combine_upright`) come from the base style.
+    /// Spans whose range isn't within the text, on character boundaries,
+    /// are ignored. Like `create`, this shapes through the global engine's
+    /// cache.
+    pub fn create_rich(
+        text: S,
+        base_style: &TextStyle,
+        spans: &[(Range<usize>, TextStyle)],
+        collection: &FontCollection,
+    ) -> LayoutSession<S> {
+        LayoutSession::create_rich_with_shaper(
+            text,
+            base_style,
+            spans,
+            collection,
+            Engine::global().shaper(),
+        )
+    }
+
+    /// Create a session for rich text that uses the given shaping engine.
+    pub fn create_rich_with_shaper(
+        text: S,
+        base_style: &TextStyle,
+        spans: &[(Range<usize>, TextStyle)],
+        collection: &FontCollection,
+        shaper: impl Shaper + 'static,
+    ) -> LayoutSession<S> {
+        let text_str = text.as_ref();
+        // Does this clone mean we should take style arg by-move?
+        let mut styles = vec![base_style.clone()];
+        styles.extend(spans.iter().map(|(_, style)| style.clone()));
+        let style_runs = style_runs(text_str, spans);
+        let level_runs = level_runs(text_str, base_style.bidi);
+        let script_runs = script_runs(text_str);
+        let fragments = shape_region(
+            text_str,
+            0..text_str.len(),
+            &level_runs,
+            &script_runs,
+            &style_runs,
+            &styles,
+            collection,
+            &shaper,
+        );
+        let order = fragment_order(&fragments);
         LayoutSession {
             text,
-            // Does this clone mean we should take style arg by-move?
-            style: style.clone(),
+            styles,
+            shaper: Box::new(shaper),
             fragments,
-            substr_fragments,
+            order,
+            substr_fragments: Vec::new(),
+            substr_order: Vec::new(),
         }
     }
 
+    /// The text being laid out.
+    pub fn text(&self) -> &str {
+        self.text.as_ref()
+    }
+
+    /// The writing mode of the paragraph.
+    pub fn writing_mode(&self) -> WritingMode {
+        self.styles[0].writing_mode
+    }
+
     /// Iterate through all glyphs in the layout.
     ///
     /// Note: this is redundant with `iter_substr` with the whole string, might
     /// not keep it.
-    pub fn iter_all(&self) -> LayoutRangeIter {
+    pub fn iter_all(&self) -> LayoutRangeIter<'_> {
         LayoutRangeIter {
+            styles: &self.styles,
             offset: Vector2F::zero(),
             fragments: &self.fragments,
-            fragment_ix: 0,
+            order: &self.order,
+            order_ix: 0,
         }
     }
 
+    /// The glyphs of the whole layout, with absolute positions.
+    pub fn to_layout(&self) -> Layout {
+        let mut glyphs = Vec::new();
+        let mut advance = Vector2F::zero();
+        for run in self.iter_all() {
+            for glyph in run.glyphs() {
+                glyphs.push(Glyph {
+                    font: run.font().clone(),
+                    glyph_id: glyph.glyph_id,
+                    offset: glyph.offset,
+                });
+            }
+            advance += run.fragment.advance;
+        }
+        Layout {
+            size: self.styles[0].size,
+            glyphs,
+            advance,
+        }
+    }
+
+    /// The advance of the substring, as laid out by `iter_substr`.
+    pub fn advance_substr(&mut self, range: Range<usize>) -> Vector2F {
+        self.advance_substr_hyphenated(range, HyphenEdit::default())
+    }
+
+    /// The advance of the substring with hyphens added, as laid out by
+    /// `iter_substr_hyphenated`.
+    pub fn advance_substr_hyphenated(
+        &mut self,
+        range: Range<usize>,
+        hyphens: HyphenEdit,
+    ) -> Vector2F {
+        self.iter_substr_hyphenated(range, hyphens)
+            .map(|run| run.fragment.advance)
+            .fold(Vector2F::zero(), |total, advance| total + advance)
+    }
+
     /// Iterate through the glyphs in the layout of the substring.
     ///
     /// This method reuses as much of the original layout as practical, almost
-    /// entirely reusing the itemization, but possibly doing re-layout.
-    pub fn iter_substr(&mut self, range: Range<usize>) -> LayoutRangeIter {
-        if range == (0..self.text.as_ref().len()) {
+    /// entirely reusing the itemization, but possibly doing re-layout. Pieces
+    /// are re-shaped with the rest of the text as context, so they keep the
+    /// joining forms they have in the full layout.
+    pub fn iter_substr(&mut self, range: Range<usize>) -> LayoutRangeIter<'_> {
+        self.iter_substr_hyphenated(range, HyphenEdit::default())
+    }
+
+    /// Iterate through the glyphs in the layout of the substring, with
+    /// hyphens added at its ends.
+    ///
+    /// A hyphen is shaped together with the text next to it, in its font,
+    /// so this measures a line ending at a hyphenation break correctly.
+    pub fn iter_substr_hyphenated(
+        &mut self,
+        range: Range<usize>,
+        hyphens: HyphenEdit,
+    ) -> LayoutRangeIter<'_> {
+        if range == (0..self.text.as_ref().len()) && hyphens.is_empty() {
             return self.iter_all();
         }
+        let start = if hyphens.prepend {
+            SubstrEdge::Hyphen
+        } else {
+            SubstrEdge::Text
+        };
+        let end = if hyphens.append {
+            SubstrEdge::Hyphen
+        } else {
+            SubstrEdge::Text
+        };
+        self.substr_fragments.clear();
+        self.push_substr(range, start, end);
+        self.substr_order = fragment_order(&self.substr_fragments);
+        LayoutRangeIter {
+            styles: &self.styles,
+            offset: Vector2F::zero(),
+            fragments: &self.substr_fragments,
+            order: &self.substr_order,
+            order_ix: 0,
+        }
+    }
+
+    /// Truncate the text with an ellipsis to fit in `max_width`, along the
+    /// inline axis.
+    ///
+    /// Whole graphemes are removed from the end, the start or the middle of
+    /// the text in logical order, so a right-to-left label loses text on its
+    /// left at the end. The ellipsis is shaped in the font of the text next
+    /// to it, as "..." if the font doesn't have "…". If the text fits, it's
+    /// laid out whole, and if even the ellipsis alone doesn't, that's all
+    /// that's left.
+    pub fn truncate(&mut self, max_width: f32, position: TruncatePosition) -> Truncation<'_> {
+        let text = self.text.as_ref();
+        let text_len = text.len();
+        let mut bounds: Vec<usize> = text.grapheme_indices(true).map(|(ix, _)| ix).collect();
+        bounds.push(text_len);
+        let n_graphemes = bounds.len() - 1;
+        let writing_mode = self.writing_mode();
+        if n_graphemes == 0 || inline_width(writing_mode, &self.fragments) <= max_width {
+            let all = 0..text_len;
+            return Truncation {
+                runs: self.iter_all(),
+                ranges: vec![all],
+            };
+        }
+        // The text kept before and after the ellipsis, with `n` graphemes
+        // removed.
+        let kept = |n: usize| match position {
+            TruncatePosition::Start => (0..0, bounds[n]..text_len),
+            TruncatePosition::Middle => {
+                let n_before = (n_graphemes - n).div_ceil(2);
+                let n_after = n_graphemes - n - n_before;
+                (0..bounds[n_before], bounds[n_graphemes - n_after]..text_len)
+            }
+            TruncatePosition::End => (0..bounds[n_graphemes - n], text_len..text_len),
+        };
+        // Find the fewest graphemes to remove.
+        let mut lo = 1;
+        let mut hi = n_graphemes;
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            let (before, after) = kept(mid);
+            self.layout_truncated(before, after);
+            if inline_width(writing_mode, &self.substr_fragments) <= max_width {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        let (before, after) = kept(lo);
+        self.layout_truncated(before.clone(), after.clone());
+        self.substr_order = fragment_order(&self.substr_fragments);
+        let ranges = vec![before, after]
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect();
+        Truncation {
+            runs: LayoutRangeIter {
+                styles: &self.styles,
+                offset: Vector2F::zero(),
+                fragments: &self.substr_fragments,
+                order: &self.substr_order,
+                order_ix: 0,
+            },
+            ranges,
+        }
+    }
+
+    // Lay out the kept text with an ellipsis between.
+    fn layout_truncated(&mut self, before: Range<usize>, after: Range<usize>) {
+        self.substr_fragments.clear();
+        if before.is_empty() {
+            self.push_substr(after, SubstrEdge::Ellipsis, SubstrEdge::Text);
+        } else {
+            self.push_substr(before, SubstrEdge::Text, SubstrEdge::Ellipsis);
+            self.push_substr(after, SubstrEdge::Cut, SubstrEdge::Text);
+        }
+    }
+
+    // Shape the pieces of a substring, in logical order, onto
+    // `substr_fragments`.
+    fn push_substr(&mut self, range: Range<usize>, start: SubstrEdge, end: SubstrEdge) {
         // TODO: reuse existing layout if unsafe_to_break flag is false at both endpoints.
         let mut fragment_ix = 0;
         let mut str_offset = 0;
@@ -121,26 +481,246 @@ impl<S: AsRef<str>> LayoutSession<S> {
             str_offset += fragment_len;
             fragment_ix += 1;
         }
-        self.substr_fragments.clear();
+        let text = self.text.as_ref();
+        if range.is_empty() {
+            // Only what's added at the ends is shown, in the font of the
+            // text there.
+            let fragment = match self.fragments.get(fragment_ix).or(self.fragments.last()) {
+                Some(fragment) => fragment,
+                None => return,
+            };
+            let font = &fragment.font;
+            let script = fragment.props.script;
+            let added = [
+                edge_text(start, script, font, true),
+                edge_text(end, script, font, false),
+            ]
+            .concat();
+            if !added.is_empty() {
+                let mut substr_fragment = LayoutFragment::shape(
+                    &*self.shaper,
+                    &self.styles,
+                    font,
+                    fragment.props,
+                    &added,
+                    "",
+                    "",
+                );
+                substr_fragment.substr_len = 0;
+                self.substr_fragments.push(substr_fragment);
+            }
+            return;
+        }
         while str_offset < range.end {
             let fragment = &self.fragments[fragment_ix];
             let fragment_len = fragment.substr_len;
             let substr_start = range.start.max(str_offset);
             let substr_end = range.end.min(str_offset + fragment_len);
-            let substr = &self.text.as_ref()[substr_start..substr_end];
+            let substr = &text[substr_start..substr_end];
             let font = &fragment.font;
-            let script = fragment.script;
+            let script = fragment.props.script;
+            // Anything but the rest of the text replaces the context on its
+            // side.
+            let (before, pre_context) = if substr_start == range.start && start != SubstrEdge::Text
+            {
+                (edge_text(start, script, font, true), "")
+            } else {
+                (String::new(), &text[..substr_start])
+            };
+            let (after, post_context) = if substr_end == range.end && end != SubstrEdge::Text {
+                (edge_text(end, script, font, false), "")
+            } else {
+                (String::new(), &text[substr_end..])
+            };
+            let shaped_text;
+            let substr = if before.is_empty() && after.is_empty() {
+                substr
+            } else {
+                shaped_text = [&before, substr, &after].concat();
+                &shaped_text
+            };
             // TODO: we should pass in the hb_face too, just for performance.
-            let substr_fragment = layout_fragment(&self.style, font, script, substr);
+            let mut substr_fragment = LayoutFragment::shape(
+                &*self.shaper,
+                &self.styles,
+                font,
+                fragment.props,
+                substr,
+                pre_context,
+                post_context,
+            );
+            substr_fragment.substr_len = substr_end - substr_start;
             self.substr_fragments.push(substr_fragment);
             str_offset += fragment_len;
             fragment_ix += 1;
         }
-        LayoutRangeIter {
-            offset: Vector2F::zero(),
-            fragments: &self.substr_fragments,
-            fragment_ix: 0,
+    }
+}
+
+impl LayoutSession<String> {
+    /// Replace a range of the text, as in an editor, re-shaping only what
+    /// the edit affects.
+    ///
+    /// The fragments touching the edit are itemized and shaped again,
+    /// extended to boundaries that are safe to break and to any fragment
+    /// whose bidi level changed; the rest are kept. Inserted text has the
+    /// style of the text before it. Combined upright ranges after the edit
+    /// move with the text, and those overlapping it are dropped.
+    ///
+    /// Panics if the range isn't on character boundaries, like
+    /// `String::replace_range`.
+    pub fn replace_range(
+        &mut self,
+        range: Range<usize>,
+        new_text: &str,
+        collection: &FontCollection,
+    ) {
+        // Offsets of the fragments in the old text.
+        let mut starts = Vec::with_capacity(self.fragments.len() + 1);
+        let mut offset = 0;
+        for fragment in &self.fragments {
+            starts.push(offset);
+            offset += fragment.substr_len;
+        }
+        starts.push(offset);
+        let n_fragments = self.fragments.len();
+        // The fragments touching the edit, including those ending at its
+        // start and starting at its end.
+        let mut lo = (0..n_fragments)
+            .find(|&ix| starts[ix + 1] >= range.start)
+            .unwrap_or(n_fragments);
+        let mut hi = (0..n_fragments)
+            .rfind(|&ix| starts[ix] <= range.end)
+            .map_or(0, |ix| ix + 1)
+            .max(lo);
+        let inserted_style = self.style_at(&starts, range.start, range.end);
+
+        self.text.replace_range(range.clone(), new_text);
+        let new_end = range.start + new_text.len();
+        // Moves an offset after the edit.
+        let shift = |offset: usize| offset - range.end + new_end;
+        let base_style = &mut self.styles[0];
+        base_style.combine_upright = base_style
+            .combine_upright
+            .iter()
+            .filter(|upright| upright.end <= range.start || upright.start >= range.end)
+            .map(|upright| {
+                if upright.start >= range.end {
+                    shift(upright.start)..shift(upright.end)
+                } else {
+                    upright.clone()
+                }
+            })
+            .collect();
+
+        // Bidi levels can change anywhere in the paragraph, and so can
+        // scripts, as closing brackets take the script of their opening one.
+        // Resolve both again for the whole text, and re-shape the fragments
+        // that changed.
+        let text = self.text.as_str();
+        let level_runs = level_runs(text, self.styles[0].bidi);
+        let script_runs = script_runs(text);
+        let script_run_at = |offset: usize| {
+            script_runs.get(script_runs.partition_point(|(run, _)| run.end <= offset))
+        };
+        for ix in (0..lo).chain(hi..n_fragments) {
+            // Fragments kept before the edit end before it, and those after
+            // start after it.
+            let start = if starts[ix] < range.start {
+                starts[ix]
+            } else {
+                shift(starts[ix])
+            };
+            let fragment_range = start..start + self.fragments[ix].substr_len;
+            let same_script = match script_run_at(fragment_range.start) {
+                Some((run_range, script)) => {
+                    *script == self.fragments[ix].props.script
+                        && run_range.end >= fragment_range.end
+                }
+                None => false,
+            };
+            match level_run_at(&level_runs, fragment_range.start) {
+                Some((run_range, level, paragraph))
+                    if *level == self.fragments[ix].props.level
+                        && run_range.end >= fragment_range.end
+                        && same_script =>
+                {
+                    self.fragments[ix].props.paragraph = *paragraph;
+                }
+                _ => {
+                    lo = lo.min(ix);
+                    hi = hi.max(ix + 1);
+                }
+            }
+        }
+        // Extend to boundaries where the shaping doesn't depend on the
+        // other side, and that a fresh layout would also have: one that
+        // only split a script run is gone if the run now spans it.
+        let fragments = &self.fragments;
+        let joined = |ix: usize, offset: usize| {
+            same_item(&fragments[ix - 1].props, &fragments[ix].props)
+                && script_run_at(offset).is_some_and(|(run_range, _)| run_range.start < offset)
+        };
+        while lo > 0
+            && (!safe_to_break(&fragments[lo - 1], &fragments[lo]) || joined(lo, starts[lo]))
+        {
+            lo -= 1;
+        }
+        while hi < n_fragments
+            && (!safe_to_break(&fragments[hi - 1], &fragments[hi]) || joined(hi, shift(starts[hi])))
+        {
+            hi += 1;
+        }
+
+        // The styles of the region, in the new text.
+        let region = starts[lo]..shift(starts[hi]);
+        let mut style_runs: Vec<(Range<usize>, usize)> = Vec::new();
+        let mut push_style = |run: Range<usize>, style: usize| {
+            if run.is_empty() {
+                return;
+            }
+            match style_runs.last_mut() {
+                Some((last, last_style)) if *last_style == style => last.end = run.end,
+                _ => style_runs.push((run, style)),
+            }
+        };
+        for ix in lo..hi {
+            let start = starts[ix];
+            let end = starts[ix + 1].min(range.start).max(start);
+            push_style(start..end, self.fragments[ix].props.style);
+        }
+        push_style(range.start..new_end, inserted_style);
+        for ix in lo..hi {
+            let start = starts[ix].max(range.end);
+            let end = starts[ix + 1].max(start);
+            push_style(shift(start)..shift(end), self.fragments[ix].props.style);
+        }
+        if style_runs.is_empty() {
+            // The whole text was deleted.
+            style_runs.push((region.clone(), 0));
         }
+
+        let fragments = shape_region(
+            text,
+            region,
+            &level_runs,
+            &script_runs,
+            &style_runs,
+            &self.styles,
+            collection,
+            &*self.shaper,
+        );
+        self.fragments.splice(lo..hi, fragments);
+        self.order = fragment_order(&self.fragments);
+    }
+
+    // The style of the text before an edit, or after it at the start.
+    fn style_at(&self, starts: &[usize], start: usize, end: usize) -> usize {
+        let before = (0..self.fragments.len()).rfind(|&ix| starts[ix] < start);
+        let after = (0..self.fragments.len()).find(|&ix| starts[ix + 1] > end);
+        before
+            .or(after)
+            .map_or(0, |ix| self.fragments[ix].props.style)
     }
 }
 
@@ -148,14 +728,19 @@ impl<'a> Iterator for LayoutRangeIter<'a> {
     type Item = LayoutRun<'a>;
 
     fn next(&mut self) -> Option<LayoutRun<'a>> {
-        if self.fragment_ix == self.fragments.len() {
+        if self.order_ix == self.order.len() {
             None
         } else {
-            let fragment = &self.fragments[self.fragment_ix];
-            self.fragment_ix += 1;
+            let fragment = &self.fragments[self.order[self.order_ix]];
+            self.order_ix += 1;
             let offset = self.offset;
             self.offset += fragment.advance;
-            Some(LayoutRun { offset, fragment })
+            let style = &self.styles[fragment.props.style];
+            Some(LayoutRun {
+                offset,
+                fragment,
+                style,
+            })
         }
     }
 }
@@ -165,6 +750,35 @@ impl<'a> LayoutRun<'a> {
         &self.fragment.font
     }
 
+    /// The style of the run, which may be a span's in a rich text session.
+    pub fn style(&self) -> &'a TextStyle {
+        self.style
+    }
+
+    /// The bidi embedding level of the run. Odd levels are right to left.
+    pub fn level(&self) -> u8 {
+        self.fragment.props.level
+    }
+
+    /// The direction the run was shaped in. In vertical writing modes, the
+    /// run's advance is downward.
+    pub fn direction(&self) -> Direction {
+        self.fragment.direction
+    }
+
+    /// How the run's glyphs are set. Sideways runs have been shaped
+    /// horizontally, and their glyphs need to be drawn rotated.
+    pub fn orientation(&self) -> Orientation {
+        self.fragment.props.orientation
+    }
+
+    /// The factor by which glyphs should be narrowed when they are drawn.
+    /// This is 1 except for text combined upright that doesn't fit in one em
+    /// even with the width variant forms.
+    pub fn horizontal_scale(&self) -> f32 {
+        self.fragment.horizontal_scale
+    }
+
     pub fn glyphs(&self) -> RunIter<'a> {
         RunIter {
             offset: self.offset,
@@ -191,38 +805,862 @@ impl<'a> Iterator for RunIter<'a> {
     }
 }
 
-/// Figure out the script for the initial part of the buffer, and also
-/// return the length of the run where that script is valid.
-pub(crate) fn get_script_run(text: &str) -> (hb_script_t, usize) {
-    let mut char_iter = text.chars();
-    if let Some(cp) = char_iter.next() {
-        let mut current_script = lookup_script(cp.into());
-        let mut len = cp.len_utf8();
-        while let Some(cp) = char_iter.next() {
-            let script = lookup_script(cp.into());
-            if script != current_script {
-                if current_script == HB_SCRIPT_INHERITED || current_script == HB_SCRIPT_COMMON {
-                    current_script = script;
-                } else if script != HB_SCRIPT_INHERITED && script != HB_SCRIPT_COMMON {
+/// Split the text into runs of a single style, given as an index into the
+/// base style followed by the span styles.
+fn style_runs(text: &str, spans: &[(Range<usize>, TextStyle)]) -> Vec<(Range<usize>, usize)> {
+    let valid = |range: &Range<usize>| text.get(range.start..range.end).is_some();
+    let mut bounds = vec![0, text.len()];
+    for (range, _) in spans.iter().filter(|(range, _)| valid(range)) {
+        bounds.push(range.start);
+        bounds.push(range.end);
+    }
+    bounds.sort_unstable();
+    bounds.dedup();
+    let mut runs: Vec<(Range<usize>, usize)> = Vec::new();
+    for pair in bounds.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let style = spans
+            .iter()
+            .rposition(|(range, _)| valid(range) && range.start <= start && end <= range.end)
+            .map_or(0, |ix| ix + 1);
+        match runs.last_mut() {
+            Some((range, run_style)) if *run_style == style => range.end = end,
+            _ => runs.push((start..end, style)),
+        }
+    }
+    runs
+}
+
+fn fragment_direction(writing_mode: WritingMode, props: ItemProps) -> Direction {
+    let vertical = writing_mode.is_vertical()
+        && props.orientation == Orientation::Upright
+        && !props.combine_upright;
+    match (vertical, props.level & 1 == 0) {
+        (false, true) => Direction::LeftToRight,
+        (false, false) => Direction::RightToLeft,
+        (true, true) => Direction::TopToBottom,
+        (true, false) => Direction::BottomToTop,
+    }
+}
+
+/// The visual order of fragments given in logical order. Each paragraph is
+/// reordered on its own.
+fn fragment_order(fragments: &[LayoutFragment]) -> Vec<usize> {
+    let mut order = Vec::with_capacity(fragments.len());
+    let mut start = 0;
+    while start < fragments.len() {
+        let paragraph = fragments[start].props.paragraph;
+        let len = fragments[start..]
+            .iter()
+            .take_while(|fragment| fragment.props.paragraph == paragraph)
+            .count();
+        let levels: Vec<u8> = fragments[start..start + len]
+            .iter()
+            .map(|fragment| fragment.props.level)
+            .collect();
+        order.extend(visual_order(&levels).into_iter().map(|ix| start + ix));
+        start += len;
+    }
+    order
+}
+
+/// The bidi level runs of the whole text, with the index of their
+/// paragraph.
+fn level_runs(text: &str, base: Option<BaseDirection>) -> Vec<(Range<usize>, u8, usize)> {
+    let mut runs = Vec::new();
+    for (paragraph, para) in bidi_paragraphs(text, base).into_iter().enumerate() {
+        for (range, level) in para.runs {
+            runs.push((range, level, paragraph));
+        }
+    }
+    runs
+}
+
+/// The script runs of the text, with their ranges.
+fn script_runs(text: &str) -> Vec<(Range<usize>, Script)> {
+    let mut runs = Vec::new();
+    let mut i = 0;
+    for (script, script_len) in ScriptRuns::new(text) {
+        runs.push((i..i + script_len, script));
+        i += script_len;
+    }
+    runs
+}
+
+/// Itemize a region of the text, and shape the items.
+///
+/// The level and script runs cover the whole text, and the style runs the
+/// region. Script runs depend on the text before the region, through the
+/// brackets left open there, so they can't be found from the region alone.
+#[allow(clippy::too_many_arguments)]
+fn shape_region(
+    text: &str,
+    region: Range<usize>,
+    level_runs: &[(Range<usize>, u8, usize)],
+    script_runs: &[(Range<usize>, Script)],
+    style_runs: &[(Range<usize>, usize)],
+    styles: &[TextStyle],
+    collection: &FontCollection,
+    shaper: &dyn Shaper,
+) -> Vec<LayoutFragment> {
+    let region_text = &text[region.clone()];
+    let base_style = &styles[0];
+    let vertical_runs = if base_style.writing_mode.is_vertical() {
+        let combine_upright: Vec<Range<usize>> = base_style
+            .combine_upright
+            .iter()
+            .filter(|range| range.start >= region.start && range.end <= region.end)
+            .map(|range| range.start - region.start..range.end - region.start)
+            .collect();
+        let mut runs = vertical_runs(region_text, &combine_upright);
+        for run in &mut runs {
+            run.range = region.start + run.range.start..region.start + run.range.end;
+        }
+        runs
+    } else {
+        vec![VerticalRun {
+            range: region.clone(),
+            orientation: Orientation::Upright,
+            combine_upright: false,
+        }]
+    };
+    let mut fragments = Vec::new();
+    let mut script_ix = script_runs.partition_point(|(run, _)| run.end <= region.start);
+    let mut vertical_ix = 0;
+    let mut style_ix = 0;
+    // The end of the text shaped so far, which can be past the end of a
+    // level run if it ended inside a range combined upright.
+    let mut done = region.start;
+    for (level_range, level, paragraph) in level_runs {
+        // Split the part of the level run in the region where the script,
+        // vertical run or style changes.
+        let mut start = level_range.start.max(done);
+        let level_end = level_range.end.min(region.end);
+        while start < level_end {
+            while script_runs[script_ix].0.end <= start {
+                script_ix += 1;
+            }
+            while vertical_runs[vertical_ix].range.end <= start {
+                vertical_ix += 1;
+            }
+            while style_runs[style_ix].0.end <= start {
+                style_ix += 1;
+            }
+            let (script_range, script) = &script_runs[script_ix];
+            let vertical_run = &vertical_runs[vertical_ix];
+            let (style_range, style_index) = &style_runs[style_ix];
+            // A range combined upright goes in a single cell, so it's one
+            // item, with the properties at its start.
+            let end = if vertical_run.combine_upright {
+                vertical_run.range.end
+            } else {
+                level_end
+                    .min(script_range.end)
+                    .min(vertical_run.range.end)
+                    .min(style_range.end)
+            };
+            let props = ItemProps {
+                script: *script,
+                level: *level,
+                paragraph: *paragraph,
+                orientation: vertical_run.orientation,
+                combine_upright: vertical_run.combine_upright,
+                style: *style_index,
+            };
+            let substr = &text[start..end];
+            let locales = &styles[*style_index].locales;
+            let items = collection.itemize_script(substr, *script, locales);
+            let items: Vec<(Range<usize>, FontRef)> = if vertical_run.combine_upright {
+                // All in the font of the first character.
+                items
+                    .take(1)
+                    .map(|(_, font)| (0..substr.len(), font))
+                    .collect()
+            } else {
+                items.collect()
+            };
+            for (range, font) in items {
+                let range = start + range.start..start + range.end;
+                let fragment = LayoutFragment::shape(
+                    shaper,
+                    styles,
+                    &font,
+                    props,
+                    &text[range.clone()],
+                    &text[..range.start],
+                    &text[range.end..],
+                );
+                fragments.push(fragment);
+            }
+            start = end;
+        }
+        done = done.max(start);
+    }
+    fragments
+}
+
+/// The level run containing the offset.
+fn level_run_at(
+    level_runs: &[(Range<usize>, u8, usize)],
+    offset: usize,
+) -> Option<&(Range<usize>, u8, usize)> {
+    level_runs
+        .binary_search_by(|(range, _, _)| {
+            if range.end <= offset {
+                Ordering::Less
+            } else if range.start > offset {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|ix| &level_runs[ix])
+}
+
+/// Whether the fragments were itemized the same other than by script, so
+/// that only a script run boundary could have split them.
+fn same_item(a: &ItemProps, b: &ItemProps) -> bool {
+    a.level == b.level
+        && a.paragraph == b.paragraph
+        && a.orientation == b.orientation
+        && a.combine_upright == b.combine_upright
+        && a.style == b.style
+}
+
+/// Whether the fragments, adjacent in logical order, were shaped without
+/// depending on each other, going by HarfBuzz's unsafe-to-break flags at
+/// their ends.
+fn safe_to_break(before: &LayoutFragment, after: &LayoutFragment) -> bool {
+    let last_cluster = before.glyphs.iter().map(|glyph| glyph.cluster).max();
+    let before_safe = before
+        .glyphs
+        .iter()
+        .filter(|glyph| Some(glyph.cluster) == last_cluster)
+        .all(|glyph| !glyph.unsafe_to_break);
+    let after_safe = after
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.cluster == 0)
+        .all(|glyph| !glyph.unsafe_to_break);
+    before_safe && after_safe
+}
+
+/// The total advance of the fragments along the inline axis.
+fn inline_width(writing_mode: WritingMode, fragments: &[LayoutFragment]) -> f32 {
+    let advance = fragments
+        .iter()
+        .fold(Vector2F::zero(), |total, fragment| total + fragment.advance);
+    if writing_mode.is_vertical() {
+        advance.y()
+    } else {
+        advance.x()
+    }
+}
+
+/// The text added to a substring at one end.
+fn edge_text(edge: SubstrEdge, script: Script, font: &FontRef, at_start: bool) -> String {
+    match edge {
+        SubstrEdge::Text | SubstrEdge::Cut => String::new(),
+        SubstrEdge::Hyphen if at_start => hyphen_before(script, font),
+        SubstrEdge::Hyphen => hyphen_after(script, font),
+        SubstrEdge::Ellipsis if font.supports_codepoint('\u{2026}') => "\u{2026}".to_string(),
+        SubstrEdge::Ellipsis => "...".to_string(),
+    }
+}
+
+/// Maximum number of open brackets remembered, as in ICU.
+const MAX_OPEN_BRACKETS: usize = 32;
+
+/// An iterator over the script runs of a string, yielding each run's script
+/// and its length in bytes.
+///
+/// A closing bracket gets the script of the run its opening bracket was in
+/// (UAX #24), so this keeps a stack of open brackets across runs, like ICU's
+/// `usc_ScriptRun`.
+pub(crate) struct ScriptRuns<'a> {
+    text: &'a str,
+    // Opening bracket and the script of its run.
+    brackets: Vec<(u32, Script)>,
+}
+
+impl<'a> ScriptRuns<'a> {
+    pub(crate) fn new(text: &'a str) -> ScriptRuns<'a> {
+        ScriptRuns {
+            text,
+            brackets: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Iterator for ScriptRuns<'a> {
+    type Item = (Script, usize);
+
+    fn next(&mut self) -> Option<(Script, usize)> {
+        if self.text.is_empty() {
+            return None;
+        }
+        // As in ICU and Chromium, a run continues as long as some script is
+        // in the Script_Extensions of all of its characters. `scripts` holds
+        // those candidates; it is empty while the run has only Common and
+        // Inherited characters, which go with any script.
+        let mut scripts: Vec<Script> = Vec::new();
+        // The first script that a character has as its own Script property.
+        let mut primary = SCRIPT_COMMON;
+        // Brackets opened in this run are pushed from here on, and get the
+        // script of the run once it's known.
+        let mut fixup = self.brackets.len();
+        let mut len = 0;
+        for (ix, cp) in self.text.char_indices() {
+            let mut exts = lookup_script_extensions(cp.into());
+            let bracket = lookup_paired_bracket(cp.into());
+            let mut matched = None;
+            let open_script;
+            if let Some((opening, false)) = bracket {
+                matched = self.brackets.iter().rposition(|&(b, _)| b == opening);
+                if let Some(pos) = matched {
+                    open_script = [self.brackets[pos].1];
+                    if pos < fixup {
+                        exts = &open_script;
+                    }
+                }
+            }
+            if exts != [SCRIPT_COMMON] && exts != [SCRIPT_INHERITED] {
+                if scripts.is_empty() {
+                    scripts.extend_from_slice(exts);
+                } else if scripts.iter().any(|s| exts.contains(s)) {
+                    scripts.retain(|s| exts.contains(s));
+                } else {
                     break;
                 }
+                let script = lookup_script(cp.into());
+                let is_own = script != SCRIPT_COMMON && script != SCRIPT_INHERITED;
+                if primary == SCRIPT_COMMON && is_own {
+                    primary = script;
+                }
             }
-            len += cp.len_utf8();
+            match (bracket, matched) {
+                (Some((opening, true)), _) => {
+                    if self.brackets.len() == MAX_OPEN_BRACKETS {
+                        self.brackets.remove(0);
+                        fixup = fixup.saturating_sub(1);
+                    }
+                    self.brackets.push((opening, SCRIPT_COMMON));
+                }
+                // Brackets left open inside the pair are dropped with it.
+                (_, Some(pos)) => self.brackets.truncate(pos),
+                _ => (),
+            }
+            len = ix + cp.len_utf8();
         }
-        if current_script == HB_SCRIPT_INHERITED {
-            current_script = HB_SCRIPT_COMMON;
+        let script = if scripts.contains(&primary) {
+            primary
+        } else if scripts.len() == 1 {
+            scripts[0]
+        } else {
+            SCRIPT_COMMON
+        };
+        let fixup = fixup.min(self.brackets.len());
+        for bracket in &mut self.brackets[fixup..] {
+            bracket.1 = script;
         }
-        (current_script, len)
-    } else {
-        (HB_SCRIPT_UNKNOWN, 0)
+        self.text = &self.text[len..];
+        Some((script, len))
     }
 }
 
-fn debug_script_runs(text: &str) {
-    let mut text_substr = text;
-    while !text_substr.is_empty() {
-        let (script, len) = get_script_run(text_substr);
-        println!("text {:?} script {:x}", &text_substr[..len], script);
-        text_substr = &text_substr[len..];
+/// The opening bracket of the pair `c` belongs to, and whether `c` is the
+/// opening one, for characters with a Bidi_Paired_Bracket.
+fn lookup_paired_bracket(c: u32) -> Option<(u32, bool)> {
+    PAIRED_BRACKET_KEY
+        .binary_search(&c)
+        .ok()
+        .map(|ix| (PAIRED_BRACKET_VAL[ix], PAIRED_BRACKET_VAL[ix] == c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::script::{
+        SCRIPT_ARABIC, SCRIPT_BENGALI, SCRIPT_DEVANAGARI, SCRIPT_HAN, SCRIPT_HEBREW,
+        SCRIPT_HIRAGANA, SCRIPT_KATAKANA, SCRIPT_LATIN,
+    };
+    use crate::test_util::{collection, FixedShaper, ESTEDAD, MADA};
+
+    fn script_runs(text: &str) -> Vec<(&str, Script)> {
+        let mut rest = text;
+        ScriptRuns::new(text)
+            .map(|(script, len)| {
+                let (run, tail) = rest.split_at(len);
+                rest = tail;
+                (run, script)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn danda() {
+        // The danda is Common, but only used with Indic scripts, so it
+        // stays in the Devanagari run rather than joining the Bengali one.
+        assert_eq!(
+            script_runs("Hello नमस्ते। আমি।"),
+            vec![
+                ("Hello ", SCRIPT_LATIN),
+                ("नमस्ते। ", SCRIPT_DEVANAGARI),
+                ("আমি।", SCRIPT_BENGALI),
+            ]
+        );
+    }
+
+    #[test]
+    fn prolonged_sound_mark() {
+        // U+30FC is used with both kana scripts, but not with Han.
+        assert_eq!(
+            script_runs("ひらがなーカタカナー漢字 and ーカナ"),
+            vec![
+                ("ひらがなー", SCRIPT_HIRAGANA),
+                ("カタカナー", SCRIPT_KATAKANA),
+                ("漢字 ", SCRIPT_HAN),
+                ("and ", SCRIPT_LATIN),
+                ("ーカナ", SCRIPT_KATAKANA),
+            ]
+        );
+    }
+
+    #[test]
+    fn brackets_match_opening_script() {
+        // A closing bracket goes with the run of its opening bracket.
+        assert_eq!(
+            script_runs("שלום (abc) שלום"),
+            vec![
+                ("שלום (", SCRIPT_HEBREW),
+                ("abc", SCRIPT_LATIN),
+                (") שלום", SCRIPT_HEBREW),
+            ]
+        );
+        assert_eq!(
+            script_runs("abc (שלום) def"),
+            vec![
+                ("abc (", SCRIPT_LATIN),
+                ("שלום", SCRIPT_HEBREW),
+                (") def", SCRIPT_LATIN),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_brackets() {
+        assert_eq!(
+            script_runs("abc (שלום [def] שלום) ghi"),
+            vec![
+                ("abc (", SCRIPT_LATIN),
+                ("שלום [", SCRIPT_HEBREW),
+                ("def", SCRIPT_LATIN),
+                ("] שלום", SCRIPT_HEBREW),
+                (") ghi", SCRIPT_LATIN),
+            ]
+        );
+        // A mismatched closing bracket closes the pair it matches, and the
+        // brackets left open inside it.
+        assert_eq!(
+            script_runs("abc (שלום [def) ghi] שלום"),
+            vec![
+                ("abc (", SCRIPT_LATIN),
+                ("שלום [", SCRIPT_HEBREW),
+                ("def) ghi] ", SCRIPT_LATIN),
+                ("שלום", SCRIPT_HEBREW),
+            ]
+        );
+    }
+
+    #[test]
+    fn unmatched_brackets() {
+        assert_eq!(
+            script_runs("שלום (abc"),
+            vec![("שלום (", SCRIPT_HEBREW), ("abc", SCRIPT_LATIN)]
+        );
+        // Without an opening bracket, a closing one is Common, and stays in
+        // the run before it.
+        assert_eq!(
+            script_runs("שלום) abc"),
+            vec![("שלום) ", SCRIPT_HEBREW), ("abc", SCRIPT_LATIN)]
+        );
+        assert_eq!(script_runs(") abc"), vec![(") abc", SCRIPT_LATIN)]);
+    }
+
+    #[test]
+    fn too_many_open_brackets() {
+        // Once MAX_OPEN_BRACKETS are open, the oldest is forgotten, so the
+        // final closing bracket no longer matches the Hebrew one.
+        let latin = format!(
+            "a{}b{})",
+            "[".repeat(MAX_OPEN_BRACKETS),
+            "]".repeat(MAX_OPEN_BRACKETS)
+        );
+        let text = format!("שלום ({}", latin);
+        assert_eq!(
+            script_runs(&text),
+            vec![("שלום (", SCRIPT_HEBREW), (latin.as_str(), SCRIPT_LATIN)]
+        );
+        // One fewer, and it does.
+        let latin = format!(
+            "a{}b{}",
+            "[".repeat(MAX_OPEN_BRACKETS - 1),
+            "]".repeat(MAX_OPEN_BRACKETS - 1)
+        );
+        let text = format!("שלום ({})", latin);
+        assert_eq!(
+            script_runs(&text),
+            vec![
+                ("שלום (", SCRIPT_HEBREW),
+                (latin.as_str(), SCRIPT_LATIN),
+                (")", SCRIPT_HEBREW),
+            ]
+        );
+    }
+
+    // The length, script, and glyph ids and clusters of a fragment.
+    type Fragment = (usize, Script, Vec<(u32, u32)>);
+
+    fn fragments(session: &LayoutSession<String>) -> Vec<Fragment> {
+        session
+            .fragments
+            .iter()
+            .map(|fragment| {
+                let glyphs = fragment
+                    .glyphs
+                    .iter()
+                    .map(|glyph| (glyph.glyph_id, glyph.cluster))
+                    .collect();
+                (fragment.substr_len, fragment.props.script, glyphs)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn replace_range_same_as_create() {
+        let collection = collection(&[MADA]);
+        let style = TextStyle::default();
+        let span_style = TextStyle::new(20.0);
+        // (text, span, range, replacement, span after the edit)
+        let cases = [
+            ("Привет (мир) hello", 0..0, 14..20, "abc", 0..0),
+            ("Привет (мир) hello", 0..0, 13..14, "", 0..0),
+            ("abc мир", 0..0, 4..10, "def", 0..0),
+            ("日本語 text) 漢字", 0..0, 10..10, "(", 0..0),
+            // The opening bracket is before the re-shaped fragments, and
+            // the closing one still takes its script.
+            ("Привет (мир, мир) hello", 14..35, 22..28, "abc", 14..32),
+        ];
+        for (text, span, range, new_text, new_span) in cases.iter() {
+            let spans = [(span.clone(), span_style.clone())];
+            let mut session =
+                LayoutSession::create_rich(text.to_string(), &style, &spans, &collection);
+            session.replace_range(range.clone(), new_text, &collection);
+            let mut expected = text.to_string();
+            expected.replace_range(range.clone(), new_text);
+            let spans = [(new_span.clone(), span_style.clone())];
+            let fresh = LayoutSession::create_rich(expected.clone(), &style, &spans, &collection);
+            assert_eq!(fragments(&session), fragments(&fresh), "{:?}", expected);
+        }
+    }
+
+    #[test]
+    fn layout_detects_scripts() {
+        let collection = collection(&[MADA]);
+        let style = TextStyle::default();
+        let text = "abc مرحبا";
+        let session = LayoutSession::create(text, &style, &collection);
+        let scripts: Vec<Script> = session
+            .fragments
+            .iter()
+            .map(|fragment| fragment.props.script)
+            .collect();
+        assert_eq!(scripts, [SCRIPT_LATIN, SCRIPT_ARABIC]);
+
+        let layout = crate::layout(&style, &collection, text);
+        let font = &collection.families[0].default_font().unwrap().font;
+        let nominal: Vec<u32> = text
+            .chars()
+            .map(|c| font.glyph_for_char(c).unwrap())
+            .collect();
+        let glyphs: Vec<u32> = layout.glyphs.iter().map(|glyph| glyph.glyph_id).collect();
+        // Latin needs no shaping here, but the Arabic letters all join, so
+        // none of them keep their nominal glyph.
+        assert_eq!(glyphs[..4], nominal[..4]);
+        assert!(glyphs[4..]
+            .iter()
+            .all(|glyph| !nominal[4..].contains(glyph)));
+    }
+
+    // The level of each run in visual order, and its characters from left
+    // to right, from the glyph ids `FixedShaper` gives them.
+    fn visual_runs(runs: LayoutRangeIter) -> Vec<(u8, String)> {
+        runs.map(|run| {
+            let text = run
+                .glyphs()
+                .map(|glyph| std::char::from_u32(glyph.glyph_id).unwrap())
+                .collect();
+            (run.level(), text)
+        })
+        .collect()
+    }
+
+    fn bidi_session(text: &str, base: BaseDirection) -> LayoutSession<String> {
+        let style = TextStyle {
+            bidi: Some(base),
+            ..TextStyle::default()
+        };
+        LayoutSession::create_with_shaper(
+            text.to_string(),
+            &style,
+            &collection(&[MADA]),
+            FixedShaper,
+        )
+    }
+
+    #[test]
+    fn bidi_run_order() {
+        let owned = |runs: &[(u8, &str)]| -> Vec<(u8, String)> {
+            runs.iter()
+                .map(|&(level, text)| (level, text.to_string()))
+                .collect()
+        };
+        // The space after the Hebrew is in its script run, but not its level
+        // run.
+        let mut session = bidi_session("abc שלום def", BaseDirection::LeftToRight);
+        assert_eq!(
+            visual_runs(session.iter_all()),
+            owned(&[(0, "abc "), (1, "םולש"), (0, " "), (0, "def")])
+        );
+        assert_eq!(
+            visual_runs(session.iter_substr(2..14)),
+            owned(&[(0, "c "), (1, "םולש"), (0, " "), (0, "d")])
+        );
+        assert_eq!(
+            visual_runs(session.iter_substr(6..14)),
+            owned(&[(1, "םול"), (0, " "), (0, "d")])
+        );
+
+        let mut session = bidi_session("שלום abc שלום", BaseDirection::RightToLeft);
+        assert_eq!(
+            visual_runs(session.iter_all()),
+            owned(&[(1, "םולש"), (1, " "), (2, "abc"), (1, " םולש")])
+        );
+        assert_eq!(
+            visual_runs(session.iter_substr(6..19)),
+            owned(&[(1, "ולש"), (1, " "), (2, "abc"), (1, " ם")])
+        );
+
+        // Runs are only reordered within their paragraph.
+        let session = bidi_session("שלום abc\nשלום", BaseDirection::Auto);
+        assert_eq!(
+            visual_runs(session.iter_all()),
+            owned(&[(1, "\n"), (2, "abc"), (1, " םולש"), (1, "םולש")])
+        );
+    }
+
+    // The length of each fragment, its font's family, and the advance of the
+    // layout, with a range of the text combined upright.
+    fn combined(
+        text: &str,
+        combine_upright: Range<usize>,
+        bidi: Option<BaseDirection>,
+        spans: &[(Range<usize>, TextStyle)],
+        collection: &FontCollection,
+    ) -> (Vec<(usize, String)>, Vector2F) {
+        let style = TextStyle {
+            writing_mode: WritingMode::VerticalRl,
+            combine_upright: vec![combine_upright],
+            bidi,
+            ..TextStyle::default()
+        };
+        let session = LayoutSession::create_rich(text, &style, spans, collection);
+        let cells = session
+            .fragments
+            .iter()
+            .map(|fragment| (fragment.substr_len, fragment.font.font.family_name()))
+            .collect();
+        (cells, session.to_layout().advance)
+    }
+
+    #[test]
+    fn combine_upright_is_one_item() {
+        let mada = collection(&[MADA]);
+        let (cells, advance) = combined("a12b", 1..3, None, &[], &mada);
+        assert_eq!(cells.len(), 3);
+        assert_eq!(cells[1].0, 2);
+        // A span that changes nothing doesn't split the cell.
+        let span = [(2..3, TextStyle::default())];
+        assert_eq!(combined("a12b", 1..3, None, &span, &mada), (cells, advance));
+        // Editing the text after it keeps it whole too.
+        let style = TextStyle {
+            writing_mode: WritingMode::VerticalRl,
+            combine_upright: vec![1..3, 4..5],
+            ..TextStyle::default()
+        };
+        let mut session = LayoutSession::create_rich("a12bc".to_string(), &style, &span, &mada);
+        session.replace_range(3..4, "de", &mada);
+        let style = TextStyle {
+            combine_upright: vec![1..3, 5..6],
+            ..style
+        };
+        let fresh = LayoutSession::create_rich("a12dec".to_string(), &style, &span, &mada);
+        assert_eq!(fragments(&session), fragments(&fresh));
+
+        // Nor does a change of script and bidi level.
+        let (cells, _) = combined("xaمy", 1..4, Some(BaseDirection::LeftToRight), &[], &mada);
+        let lens: Vec<usize> = cells.iter().map(|(len, _)| *len).collect();
+        assert_eq!(lens, [1, 3, 1]);
+
+        // Nor a character only another font covers: the cell is in the font
+        // of its first character.
+        let both = collection(&[MADA, ESTEDAD]);
+        let (cells, _) = combined("x4\u{fb57}y", 1..5, None, &[], &both);
+        assert_eq!(
+            cells,
+            [
+                (1, "Mada".to_string()),
+                (4, "Mada".to_string()),
+                (1, "Mada".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn substr_keeps_joining() {
+        let collection = collection(&[MADA]);
+        let style = TextStyle::default();
+        let glyph_ids = |runs: LayoutRangeIter| -> Vec<u32> {
+            runs.flat_map(|run| run.glyphs().map(|glyph| glyph.glyph_id))
+                .collect()
+        };
+        // Each letter is a dotless body and a dot that depends on the
+        // joining form, in visual order. In the full text the second letter
+        // is medial, but on its own it would be final.
+        let mut session = LayoutSession::create("بببب", &style, &collection);
+        let full = glyph_ids(session.iter_all());
+        assert_eq!(full, [111, 5, 111, 7, 111, 7, 111, 6]);
+        assert_eq!(glyph_ids(session.iter_substr(0..4)), full[4..]);
+        let alone = LayoutSession::create("بب", &style, &collection);
+        assert_eq!(glyph_ids(alone.iter_all()), [111, 5, 111, 6]);
+    }
+
+    // The characters of a truncated layout from left to right, from the
+    // glyph ids `FixedShaper` gives them, and the ranges kept as (start, end).
+    fn truncated(
+        text: &str,
+        bidi: Option<BaseDirection>,
+        collection: &FontCollection,
+        width: f32,
+        position: TruncatePosition,
+    ) -> (String, Vec<(usize, usize)>) {
+        let style = TextStyle {
+            bidi,
+            ..TextStyle::default()
+        };
+        let mut session =
+            LayoutSession::create_with_shaper(text.to_string(), &style, collection, FixedShaper);
+        let truncation = session.truncate(width, position);
+        let glyphs = truncation
+            .runs
+            .flat_map(|run| run.glyphs())
+            .map(|glyph| std::char::from_u32(glyph.glyph_id).unwrap())
+            .collect();
+        let ranges = truncation
+            .ranges
+            .iter()
+            .map(|range| (range.start, range.end))
+            .collect();
+        (glyphs, ranges)
+    }
+
+    #[test]
+    fn truncate_positions() {
+        use TruncatePosition::{End, Middle, Start};
+        let mada = collection(&[MADA]);
+        let text = "abcdefghij";
+        // Each character and the ellipsis are ADVANCE wide.
+        assert_eq!(
+            truncated(text, None, &mada, 100.0, End),
+            (text.to_string(), vec![(0, 10)])
+        );
+        assert_eq!(
+            truncated(text, None, &mada, 55.0, End),
+            ("abcd\u{2026}".to_string(), vec![(0, 4)])
+        );
+        assert_eq!(
+            truncated(text, None, &mada, 55.0, Start),
+            ("\u{2026}ghij".to_string(), vec![(6, 10)])
+        );
+        assert_eq!(
+            truncated(text, None, &mada, 55.0, Middle),
+            ("ab\u{2026}ij".to_string(), vec![(0, 2), (8, 10)])
+        );
+        // When only the ellipsis fits, no text is kept.
+        for &position in &[Start, Middle, End] {
+            assert_eq!(
+                truncated(text, None, &mada, 10.0, position),
+                ("\u{2026}".to_string(), vec![])
+            );
+        }
+    }
+
+    #[test]
+    fn truncate_keeps_graphemes() {
+        use TruncatePosition::{End, Start};
+        let mada = collection(&[MADA]);
+        // The accent is a character of its own, but not a grapheme.
+        let text = "ae\u{301}bc";
+        assert_eq!(
+            truncated(text, None, &mada, 35.0, End),
+            ("a\u{2026}".to_string(), vec![(0, 1)])
+        );
+        assert_eq!(
+            truncated(text, None, &mada, 35.0, Start),
+            ("\u{2026}bc".to_string(), vec![(4, 6)])
+        );
+        // A ZWJ sequence is five characters, and can only go as a whole.
+        let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+        let text = format!("ab{}", family);
+        assert_eq!(
+            truncated(&text, None, &mada, 40.0, End),
+            ("ab\u{2026}".to_string(), vec![(0, 2)])
+        );
+        assert_eq!(
+            truncated(&text, None, &mada, 60.0, Start),
+            (format!("\u{2026}{}", family), vec![(2, text.len())])
+        );
+        assert_eq!(
+            truncated(&text, None, &mada, 50.0, Start),
+            ("\u{2026}".to_string(), vec![])
+        );
+    }
+
+    #[test]
+    fn truncate_without_ellipsis_glyph() {
+        // Estedad has no U+2026, so three periods are used.
+        let estedad = collection(&[ESTEDAD]);
+        assert_eq!(
+            truncated("abcdefghij", None, &estedad, 75.0, TruncatePosition::End),
+            ("abcd...".to_string(), vec![(0, 4)])
+        );
+    }
+
+    #[test]
+    fn truncate_rtl() {
+        // The text is kept from the logical start, so the ellipsis is on
+        // the left.
+        let mada = collection(&[MADA]);
+        assert_eq!(
+            truncated(
+                "مرحبا",
+                Some(BaseDirection::Auto),
+                &mada,
+                40.0,
+                TruncatePosition::End
+            ),
+            ("\u{2026}حرم".to_string(), vec![(0, 6)])
+        );
     }
 }
@@ -0,0 +1,112 @@
+//! The interface between layout and a shaping engine.
+//!
+//! `LayoutSession` does itemization itself and hands each run to a `Shaper`.
+//! HarfBuzz is the default, or rustybuzz when built without HarfBuzz; other
+//! engines can be plugged in by implementing the trait.
+
+use pathfinder_geometry::vector::Vector2F;
+
+use crate::script::Script;
+use crate::FontRef;
+
+#[cfg(feature = "harfbuzz")]
+pub(crate) type DefaultShaper = crate::HarfBuzzShaper;
+#[cfg(not(feature = "harfbuzz"))]
+pub(crate) type DefaultShaper = crate::RustybuzzShaper;
+
+/// The direction a run is shaped in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+    TopToBottom,
+    BottomToTop,
+}
+
+impl Direction {
+    pub fn is_vertical(self) -> bool {
+        self == Direction::TopToBottom || self == Direction::BottomToTop
+    }
+}
+
+/// An OpenType feature setting, applied to a whole run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Feature {
+    pub tag: u32,
+    pub value: u32,
+}
+
+impl Feature {
+    /// A feature from its four-byte tag, for example `Feature::new(b"liga", 0)`
+    /// to disable standard ligatures.
+    pub fn new(tag: &[u8; 4], value: u32) -> Feature {
+        Feature {
+            tag: u32::from_be_bytes(*tag),
+            value,
+        }
+    }
+}
+
+/// Everything about a run other than its text.
+pub struct ShapeParams<'a> {
+    pub font: &'a FontRef,
+    /// The font size, in pixels per em.
+    pub size: f32,
+    pub script: Script,
+    pub direction: Direction,
+    /// BCP-47 language tag, used for language-specific forms.
+    pub language: Option<&'a str>,
+    pub features: &'a [Feature],
+    /// Text before and after the run. It isn't shaped, but the shaper looks
+    /// at it for things like Arabic joining across the run's ends.
+    pub pre_context: &'a str,
+    pub post_context: &'a str,
+}
+
+/// A shaped glyph.
+///
+/// Positions and advances are in pixels, with y growing downward, and offsets
+/// are relative to the start of the run. In vertical runs, offsets are for
+/// the glyph's horizontal origin, so it can be drawn like any other.
+#[derive(Clone, Debug)]
+pub struct ShapedGlyph {
+    /// Byte offset of the cluster within the run's text.
+    pub cluster: u32,
+    pub glyph_id: u32,
+    pub offset: Vector2F,
+    pub advance: Vector2F,
+    pub unsafe_to_break: bool,
+}
+
+/// The result of shaping a run.
+#[derive(Clone, Debug, Default)]
+pub struct ShapedRun {
+    pub glyphs: Vec<ShapedGlyph>,
+    pub advance: Vector2F,
+}
+
+/// A shaping engine.
+pub trait Shaper {
+    /// Shape a run of text in a single font, script and direction.
+    fn shape(&self, text: &str, params: &ShapeParams) -> ShapedRun;
+}
+
+// The number of characters of context HarfBuzz and rustybuzz look at, on
+// each side (HB_BUFFER_CONTEXT_LENGTH).
+const CONTEXT_CHARS: usize = 5;
+
+/// The part of the text before a run that a shaper looks at.
+pub(crate) fn context_before(text: &str) -> &str {
+    match text.char_indices().rev().nth(CONTEXT_CHARS - 1) {
+        Some((ix, _)) => &text[ix..],
+        None => text,
+    }
+}
+
+/// The part of the text after a run that a shaper looks at.
+pub(crate) fn context_after(text: &str) -> &str {
+    match text.char_indices().nth(CONTEXT_CHARS) {
+        Some((ix, _)) => &text[..ix],
+        None => text,
+    }
+}
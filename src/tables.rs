@@ -4,14 +4,15 @@
 // Those files contain the following copyright notice:
 
 // Unicode Character Database
-// Date: 2019-03-01, 22:17:00 GMT [KW]
-// © 2019 Unicode®, Inc.
+// Version 15.0.0
+// © 2022 Unicode®, Inc.
 // Unicode and the Unicode Logo are registered trademarks of Unicode, Inc. in the U.S. and other countries.
 // For terms of use, see http://www.unicode.org/terms_of_use.html
 
-use harfbuzz::sys::*;
+use crate::script::*;
+use crate::vertical::VerticalOrientation;
 
-pub const CANONICAL_DECOMP_KEY: [u32; 2060] = [
+pub static CANONICAL_DECOMP_KEY: [u32; 2061] = [
     0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc7, 0xc8, 0xc9, 0xca, 0xcb, 0xcc, 0xcd, 0xce, 0xcf, 0xd1,
     0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd9, 0xda, 0xdb, 0xdc, 0xdd, 0xe0, 0xe1, 0xe2, 0xe3, 0xe4, 0xe5,
     0xe7, 0xe8, 0xe9, 0xea, 0xeb, 0xec, 0xed, 0xee, 0xef, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf9,
@@ -134,64 +135,64 @@ pub const CANONICAL_DECOMP_KEY: [u32; 2060] = [
     0xfb31, 0xfb32, 0xfb33, 0xfb34, 0xfb35, 0xfb36, 0xfb38, 0xfb39, 0xfb3a, 0xfb3b, 0xfb3c, 0xfb3e,
     0xfb40, 0xfb41, 0xfb43, 0xfb44, 0xfb46, 0xfb47, 0xfb48, 0xfb49, 0xfb4a, 0xfb4b, 0xfb4c, 0xfb4d,
     0xfb4e, 0x1109a, 0x1109c, 0x110ab, 0x1112e, 0x1112f, 0x1134b, 0x1134c, 0x114bb, 0x114bc,
-    0x114be, 0x115ba, 0x115bb, 0x1d15e, 0x1d15f, 0x1d160, 0x1d161, 0x1d162, 0x1d163, 0x1d164,
-    0x1d1bb, 0x1d1bc, 0x1d1bd, 0x1d1be, 0x1d1bf, 0x1d1c0, 0x2f800, 0x2f801, 0x2f802, 0x2f803,
-    0x2f804, 0x2f805, 0x2f806, 0x2f807, 0x2f808, 0x2f809, 0x2f80a, 0x2f80b, 0x2f80c, 0x2f80d,
-    0x2f80e, 0x2f80f, 0x2f810, 0x2f811, 0x2f812, 0x2f813, 0x2f814, 0x2f815, 0x2f816, 0x2f817,
-    0x2f818, 0x2f819, 0x2f81a, 0x2f81b, 0x2f81c, 0x2f81d, 0x2f81e, 0x2f81f, 0x2f820, 0x2f821,
-    0x2f822, 0x2f823, 0x2f824, 0x2f825, 0x2f826, 0x2f827, 0x2f828, 0x2f829, 0x2f82a, 0x2f82b,
-    0x2f82c, 0x2f82d, 0x2f82e, 0x2f82f, 0x2f830, 0x2f831, 0x2f832, 0x2f833, 0x2f834, 0x2f835,
-    0x2f836, 0x2f837, 0x2f838, 0x2f839, 0x2f83a, 0x2f83b, 0x2f83c, 0x2f83d, 0x2f83e, 0x2f83f,
-    0x2f840, 0x2f841, 0x2f842, 0x2f843, 0x2f844, 0x2f845, 0x2f846, 0x2f847, 0x2f848, 0x2f849,
-    0x2f84a, 0x2f84b, 0x2f84c, 0x2f84d, 0x2f84e, 0x2f84f, 0x2f850, 0x2f851, 0x2f852, 0x2f853,
-    0x2f854, 0x2f855, 0x2f856, 0x2f857, 0x2f858, 0x2f859, 0x2f85a, 0x2f85b, 0x2f85c, 0x2f85d,
-    0x2f85e, 0x2f85f, 0x2f860, 0x2f861, 0x2f862, 0x2f863, 0x2f864, 0x2f865, 0x2f866, 0x2f867,
-    0x2f868, 0x2f869, 0x2f86a, 0x2f86b, 0x2f86c, 0x2f86d, 0x2f86e, 0x2f86f, 0x2f870, 0x2f871,
-    0x2f872, 0x2f873, 0x2f874, 0x2f875, 0x2f876, 0x2f877, 0x2f878, 0x2f879, 0x2f87a, 0x2f87b,
-    0x2f87c, 0x2f87d, 0x2f87e, 0x2f87f, 0x2f880, 0x2f881, 0x2f882, 0x2f883, 0x2f884, 0x2f885,
-    0x2f886, 0x2f887, 0x2f888, 0x2f889, 0x2f88a, 0x2f88b, 0x2f88c, 0x2f88d, 0x2f88e, 0x2f88f,
-    0x2f890, 0x2f891, 0x2f892, 0x2f893, 0x2f894, 0x2f895, 0x2f896, 0x2f897, 0x2f898, 0x2f899,
-    0x2f89a, 0x2f89b, 0x2f89c, 0x2f89d, 0x2f89e, 0x2f89f, 0x2f8a0, 0x2f8a1, 0x2f8a2, 0x2f8a3,
-    0x2f8a4, 0x2f8a5, 0x2f8a6, 0x2f8a7, 0x2f8a8, 0x2f8a9, 0x2f8aa, 0x2f8ab, 0x2f8ac, 0x2f8ad,
-    0x2f8ae, 0x2f8af, 0x2f8b0, 0x2f8b1, 0x2f8b2, 0x2f8b3, 0x2f8b4, 0x2f8b5, 0x2f8b6, 0x2f8b7,
-    0x2f8b8, 0x2f8b9, 0x2f8ba, 0x2f8bb, 0x2f8bc, 0x2f8bd, 0x2f8be, 0x2f8bf, 0x2f8c0, 0x2f8c1,
-    0x2f8c2, 0x2f8c3, 0x2f8c4, 0x2f8c5, 0x2f8c6, 0x2f8c7, 0x2f8c8, 0x2f8c9, 0x2f8ca, 0x2f8cb,
-    0x2f8cc, 0x2f8cd, 0x2f8ce, 0x2f8cf, 0x2f8d0, 0x2f8d1, 0x2f8d2, 0x2f8d3, 0x2f8d4, 0x2f8d5,
-    0x2f8d6, 0x2f8d7, 0x2f8d8, 0x2f8d9, 0x2f8da, 0x2f8db, 0x2f8dc, 0x2f8dd, 0x2f8de, 0x2f8df,
-    0x2f8e0, 0x2f8e1, 0x2f8e2, 0x2f8e3, 0x2f8e4, 0x2f8e5, 0x2f8e6, 0x2f8e7, 0x2f8e8, 0x2f8e9,
-    0x2f8ea, 0x2f8eb, 0x2f8ec, 0x2f8ed, 0x2f8ee, 0x2f8ef, 0x2f8f0, 0x2f8f1, 0x2f8f2, 0x2f8f3,
-    0x2f8f4, 0x2f8f5, 0x2f8f6, 0x2f8f7, 0x2f8f8, 0x2f8f9, 0x2f8fa, 0x2f8fb, 0x2f8fc, 0x2f8fd,
-    0x2f8fe, 0x2f8ff, 0x2f900, 0x2f901, 0x2f902, 0x2f903, 0x2f904, 0x2f905, 0x2f906, 0x2f907,
-    0x2f908, 0x2f909, 0x2f90a, 0x2f90b, 0x2f90c, 0x2f90d, 0x2f90e, 0x2f90f, 0x2f910, 0x2f911,
-    0x2f912, 0x2f913, 0x2f914, 0x2f915, 0x2f916, 0x2f917, 0x2f918, 0x2f919, 0x2f91a, 0x2f91b,
-    0x2f91c, 0x2f91d, 0x2f91e, 0x2f91f, 0x2f920, 0x2f921, 0x2f922, 0x2f923, 0x2f924, 0x2f925,
-    0x2f926, 0x2f927, 0x2f928, 0x2f929, 0x2f92a, 0x2f92b, 0x2f92c, 0x2f92d, 0x2f92e, 0x2f92f,
-    0x2f930, 0x2f931, 0x2f932, 0x2f933, 0x2f934, 0x2f935, 0x2f936, 0x2f937, 0x2f938, 0x2f939,
-    0x2f93a, 0x2f93b, 0x2f93c, 0x2f93d, 0x2f93e, 0x2f93f, 0x2f940, 0x2f941, 0x2f942, 0x2f943,
-    0x2f944, 0x2f945, 0x2f946, 0x2f947, 0x2f948, 0x2f949, 0x2f94a, 0x2f94b, 0x2f94c, 0x2f94d,
-    0x2f94e, 0x2f94f, 0x2f950, 0x2f951, 0x2f952, 0x2f953, 0x2f954, 0x2f955, 0x2f956, 0x2f957,
-    0x2f958, 0x2f959, 0x2f95a, 0x2f95b, 0x2f95c, 0x2f95d, 0x2f95e, 0x2f95f, 0x2f960, 0x2f961,
-    0x2f962, 0x2f963, 0x2f964, 0x2f965, 0x2f966, 0x2f967, 0x2f968, 0x2f969, 0x2f96a, 0x2f96b,
-    0x2f96c, 0x2f96d, 0x2f96e, 0x2f96f, 0x2f970, 0x2f971, 0x2f972, 0x2f973, 0x2f974, 0x2f975,
-    0x2f976, 0x2f977, 0x2f978, 0x2f979, 0x2f97a, 0x2f97b, 0x2f97c, 0x2f97d, 0x2f97e, 0x2f97f,
-    0x2f980, 0x2f981, 0x2f982, 0x2f983, 0x2f984, 0x2f985, 0x2f986, 0x2f987, 0x2f988, 0x2f989,
-    0x2f98a, 0x2f98b, 0x2f98c, 0x2f98d, 0x2f98e, 0x2f98f, 0x2f990, 0x2f991, 0x2f992, 0x2f993,
-    0x2f994, 0x2f995, 0x2f996, 0x2f997, 0x2f998, 0x2f999, 0x2f99a, 0x2f99b, 0x2f99c, 0x2f99d,
-    0x2f99e, 0x2f99f, 0x2f9a0, 0x2f9a1, 0x2f9a2, 0x2f9a3, 0x2f9a4, 0x2f9a5, 0x2f9a6, 0x2f9a7,
-    0x2f9a8, 0x2f9a9, 0x2f9aa, 0x2f9ab, 0x2f9ac, 0x2f9ad, 0x2f9ae, 0x2f9af, 0x2f9b0, 0x2f9b1,
-    0x2f9b2, 0x2f9b3, 0x2f9b4, 0x2f9b5, 0x2f9b6, 0x2f9b7, 0x2f9b8, 0x2f9b9, 0x2f9ba, 0x2f9bb,
-    0x2f9bc, 0x2f9bd, 0x2f9be, 0x2f9bf, 0x2f9c0, 0x2f9c1, 0x2f9c2, 0x2f9c3, 0x2f9c4, 0x2f9c5,
-    0x2f9c6, 0x2f9c7, 0x2f9c8, 0x2f9c9, 0x2f9ca, 0x2f9cb, 0x2f9cc, 0x2f9cd, 0x2f9ce, 0x2f9cf,
-    0x2f9d0, 0x2f9d1, 0x2f9d2, 0x2f9d3, 0x2f9d4, 0x2f9d5, 0x2f9d6, 0x2f9d7, 0x2f9d8, 0x2f9d9,
-    0x2f9da, 0x2f9db, 0x2f9dc, 0x2f9dd, 0x2f9de, 0x2f9df, 0x2f9e0, 0x2f9e1, 0x2f9e2, 0x2f9e3,
-    0x2f9e4, 0x2f9e5, 0x2f9e6, 0x2f9e7, 0x2f9e8, 0x2f9e9, 0x2f9ea, 0x2f9eb, 0x2f9ec, 0x2f9ed,
-    0x2f9ee, 0x2f9ef, 0x2f9f0, 0x2f9f1, 0x2f9f2, 0x2f9f3, 0x2f9f4, 0x2f9f5, 0x2f9f6, 0x2f9f7,
-    0x2f9f8, 0x2f9f9, 0x2f9fa, 0x2f9fb, 0x2f9fc, 0x2f9fd, 0x2f9fe, 0x2f9ff, 0x2fa00, 0x2fa01,
-    0x2fa02, 0x2fa03, 0x2fa04, 0x2fa05, 0x2fa06, 0x2fa07, 0x2fa08, 0x2fa09, 0x2fa0a, 0x2fa0b,
-    0x2fa0c, 0x2fa0d, 0x2fa0e, 0x2fa0f, 0x2fa10, 0x2fa11, 0x2fa12, 0x2fa13, 0x2fa14, 0x2fa15,
-    0x2fa16, 0x2fa17, 0x2fa18, 0x2fa19, 0x2fa1a, 0x2fa1b, 0x2fa1c, 0x2fa1d,
+    0x114be, 0x115ba, 0x115bb, 0x11938, 0x1d15e, 0x1d15f, 0x1d160, 0x1d161, 0x1d162, 0x1d163,
+    0x1d164, 0x1d1bb, 0x1d1bc, 0x1d1bd, 0x1d1be, 0x1d1bf, 0x1d1c0, 0x2f800, 0x2f801, 0x2f802,
+    0x2f803, 0x2f804, 0x2f805, 0x2f806, 0x2f807, 0x2f808, 0x2f809, 0x2f80a, 0x2f80b, 0x2f80c,
+    0x2f80d, 0x2f80e, 0x2f80f, 0x2f810, 0x2f811, 0x2f812, 0x2f813, 0x2f814, 0x2f815, 0x2f816,
+    0x2f817, 0x2f818, 0x2f819, 0x2f81a, 0x2f81b, 0x2f81c, 0x2f81d, 0x2f81e, 0x2f81f, 0x2f820,
+    0x2f821, 0x2f822, 0x2f823, 0x2f824, 0x2f825, 0x2f826, 0x2f827, 0x2f828, 0x2f829, 0x2f82a,
+    0x2f82b, 0x2f82c, 0x2f82d, 0x2f82e, 0x2f82f, 0x2f830, 0x2f831, 0x2f832, 0x2f833, 0x2f834,
+    0x2f835, 0x2f836, 0x2f837, 0x2f838, 0x2f839, 0x2f83a, 0x2f83b, 0x2f83c, 0x2f83d, 0x2f83e,
+    0x2f83f, 0x2f840, 0x2f841, 0x2f842, 0x2f843, 0x2f844, 0x2f845, 0x2f846, 0x2f847, 0x2f848,
+    0x2f849, 0x2f84a, 0x2f84b, 0x2f84c, 0x2f84d, 0x2f84e, 0x2f84f, 0x2f850, 0x2f851, 0x2f852,
+    0x2f853, 0x2f854, 0x2f855, 0x2f856, 0x2f857, 0x2f858, 0x2f859, 0x2f85a, 0x2f85b, 0x2f85c,
+    0x2f85d, 0x2f85e, 0x2f85f, 0x2f860, 0x2f861, 0x2f862, 0x2f863, 0x2f864, 0x2f865, 0x2f866,
+    0x2f867, 0x2f868, 0x2f869, 0x2f86a, 0x2f86b, 0x2f86c, 0x2f86d, 0x2f86e, 0x2f86f, 0x2f870,
+    0x2f871, 0x2f872, 0x2f873, 0x2f874, 0x2f875, 0x2f876, 0x2f877, 0x2f878, 0x2f879, 0x2f87a,
+    0x2f87b, 0x2f87c, 0x2f87d, 0x2f87e, 0x2f87f, 0x2f880, 0x2f881, 0x2f882, 0x2f883, 0x2f884,
+    0x2f885, 0x2f886, 0x2f887, 0x2f888, 0x2f889, 0x2f88a, 0x2f88b, 0x2f88c, 0x2f88d, 0x2f88e,
+    0x2f88f, 0x2f890, 0x2f891, 0x2f892, 0x2f893, 0x2f894, 0x2f895, 0x2f896, 0x2f897, 0x2f898,
+    0x2f899, 0x2f89a, 0x2f89b, 0x2f89c, 0x2f89d, 0x2f89e, 0x2f89f, 0x2f8a0, 0x2f8a1, 0x2f8a2,
+    0x2f8a3, 0x2f8a4, 0x2f8a5, 0x2f8a6, 0x2f8a7, 0x2f8a8, 0x2f8a9, 0x2f8aa, 0x2f8ab, 0x2f8ac,
+    0x2f8ad, 0x2f8ae, 0x2f8af, 0x2f8b0, 0x2f8b1, 0x2f8b2, 0x2f8b3, 0x2f8b4, 0x2f8b5, 0x2f8b6,
+    0x2f8b7, 0x2f8b8, 0x2f8b9, 0x2f8ba, 0x2f8bb, 0x2f8bc, 0x2f8bd, 0x2f8be, 0x2f8bf, 0x2f8c0,
+    0x2f8c1, 0x2f8c2, 0x2f8c3, 0x2f8c4, 0x2f8c5, 0x2f8c6, 0x2f8c7, 0x2f8c8, 0x2f8c9, 0x2f8ca,
+    0x2f8cb, 0x2f8cc, 0x2f8cd, 0x2f8ce, 0x2f8cf, 0x2f8d0, 0x2f8d1, 0x2f8d2, 0x2f8d3, 0x2f8d4,
+    0x2f8d5, 0x2f8d6, 0x2f8d7, 0x2f8d8, 0x2f8d9, 0x2f8da, 0x2f8db, 0x2f8dc, 0x2f8dd, 0x2f8de,
+    0x2f8df, 0x2f8e0, 0x2f8e1, 0x2f8e2, 0x2f8e3, 0x2f8e4, 0x2f8e5, 0x2f8e6, 0x2f8e7, 0x2f8e8,
+    0x2f8e9, 0x2f8ea, 0x2f8eb, 0x2f8ec, 0x2f8ed, 0x2f8ee, 0x2f8ef, 0x2f8f0, 0x2f8f1, 0x2f8f2,
+    0x2f8f3, 0x2f8f4, 0x2f8f5, 0x2f8f6, 0x2f8f7, 0x2f8f8, 0x2f8f9, 0x2f8fa, 0x2f8fb, 0x2f8fc,
+    0x2f8fd, 0x2f8fe, 0x2f8ff, 0x2f900, 0x2f901, 0x2f902, 0x2f903, 0x2f904, 0x2f905, 0x2f906,
+    0x2f907, 0x2f908, 0x2f909, 0x2f90a, 0x2f90b, 0x2f90c, 0x2f90d, 0x2f90e, 0x2f90f, 0x2f910,
+    0x2f911, 0x2f912, 0x2f913, 0x2f914, 0x2f915, 0x2f916, 0x2f917, 0x2f918, 0x2f919, 0x2f91a,
+    0x2f91b, 0x2f91c, 0x2f91d, 0x2f91e, 0x2f91f, 0x2f920, 0x2f921, 0x2f922, 0x2f923, 0x2f924,
+    0x2f925, 0x2f926, 0x2f927, 0x2f928, 0x2f929, 0x2f92a, 0x2f92b, 0x2f92c, 0x2f92d, 0x2f92e,
+    0x2f92f, 0x2f930, 0x2f931, 0x2f932, 0x2f933, 0x2f934, 0x2f935, 0x2f936, 0x2f937, 0x2f938,
+    0x2f939, 0x2f93a, 0x2f93b, 0x2f93c, 0x2f93d, 0x2f93e, 0x2f93f, 0x2f940, 0x2f941, 0x2f942,
+    0x2f943, 0x2f944, 0x2f945, 0x2f946, 0x2f947, 0x2f948, 0x2f949, 0x2f94a, 0x2f94b, 0x2f94c,
+    0x2f94d, 0x2f94e, 0x2f94f, 0x2f950, 0x2f951, 0x2f952, 0x2f953, 0x2f954, 0x2f955, 0x2f956,
+    0x2f957, 0x2f958, 0x2f959, 0x2f95a, 0x2f95b, 0x2f95c, 0x2f95d, 0x2f95e, 0x2f95f, 0x2f960,
+    0x2f961, 0x2f962, 0x2f963, 0x2f964, 0x2f965, 0x2f966, 0x2f967, 0x2f968, 0x2f969, 0x2f96a,
+    0x2f96b, 0x2f96c, 0x2f96d, 0x2f96e, 0x2f96f, 0x2f970, 0x2f971, 0x2f972, 0x2f973, 0x2f974,
+    0x2f975, 0x2f976, 0x2f977, 0x2f978, 0x2f979, 0x2f97a, 0x2f97b, 0x2f97c, 0x2f97d, 0x2f97e,
+    0x2f97f, 0x2f980, 0x2f981, 0x2f982, 0x2f983, 0x2f984, 0x2f985, 0x2f986, 0x2f987, 0x2f988,
+    0x2f989, 0x2f98a, 0x2f98b, 0x2f98c, 0x2f98d, 0x2f98e, 0x2f98f, 0x2f990, 0x2f991, 0x2f992,
+    0x2f993, 0x2f994, 0x2f995, 0x2f996, 0x2f997, 0x2f998, 0x2f999, 0x2f99a, 0x2f99b, 0x2f99c,
+    0x2f99d, 0x2f99e, 0x2f99f, 0x2f9a0, 0x2f9a1, 0x2f9a2, 0x2f9a3, 0x2f9a4, 0x2f9a5, 0x2f9a6,
+    0x2f9a7, 0x2f9a8, 0x2f9a9, 0x2f9aa, 0x2f9ab, 0x2f9ac, 0x2f9ad, 0x2f9ae, 0x2f9af, 0x2f9b0,
+    0x2f9b1, 0x2f9b2, 0x2f9b3, 0x2f9b4, 0x2f9b5, 0x2f9b6, 0x2f9b7, 0x2f9b8, 0x2f9b9, 0x2f9ba,
+    0x2f9bb, 0x2f9bc, 0x2f9bd, 0x2f9be, 0x2f9bf, 0x2f9c0, 0x2f9c1, 0x2f9c2, 0x2f9c3, 0x2f9c4,
+    0x2f9c5, 0x2f9c6, 0x2f9c7, 0x2f9c8, 0x2f9c9, 0x2f9ca, 0x2f9cb, 0x2f9cc, 0x2f9cd, 0x2f9ce,
+    0x2f9cf, 0x2f9d0, 0x2f9d1, 0x2f9d2, 0x2f9d3, 0x2f9d4, 0x2f9d5, 0x2f9d6, 0x2f9d7, 0x2f9d8,
+    0x2f9d9, 0x2f9da, 0x2f9db, 0x2f9dc, 0x2f9dd, 0x2f9de, 0x2f9df, 0x2f9e0, 0x2f9e1, 0x2f9e2,
+    0x2f9e3, 0x2f9e4, 0x2f9e5, 0x2f9e6, 0x2f9e7, 0x2f9e8, 0x2f9e9, 0x2f9ea, 0x2f9eb, 0x2f9ec,
+    0x2f9ed, 0x2f9ee, 0x2f9ef, 0x2f9f0, 0x2f9f1, 0x2f9f2, 0x2f9f3, 0x2f9f4, 0x2f9f5, 0x2f9f6,
+    0x2f9f7, 0x2f9f8, 0x2f9f9, 0x2f9fa, 0x2f9fb, 0x2f9fc, 0x2f9fd, 0x2f9fe, 0x2f9ff, 0x2fa00,
+    0x2fa01, 0x2fa02, 0x2fa03, 0x2fa04, 0x2fa05, 0x2fa06, 0x2fa07, 0x2fa08, 0x2fa09, 0x2fa0a,
+    0x2fa0b, 0x2fa0c, 0x2fa0d, 0x2fa0e, 0x2fa0f, 0x2fa10, 0x2fa11, 0x2fa12, 0x2fa13, 0x2fa14,
+    0x2fa15, 0x2fa16, 0x2fa17, 0x2fa18, 0x2fa19, 0x2fa1a, 0x2fa1b, 0x2fa1c, 0x2fa1d,
 ];
-pub const CANONICAL_DECOMP_VAL: [(u32, u32); 2060] = [
+pub static CANONICAL_DECOMP_VAL: [(u32, u32); 2061] = [
     (0x41, 0x300),
     (0x41, 0x301),
     (0x41, 0x302),
@@ -1697,6 +1698,7 @@ pub const CANONICAL_DECOMP_VAL: [(u32, u32); 2060] = [
     (0x114b9, 0x114bd),
     (0x115b8, 0x115af),
     (0x115b9, 0x115af),
+    (0x11935, 0x11930),
     (0x1d157, 0x1d165),
     (0x1d158, 0x1d165),
     (0x1d15f, 0x1d16e),
@@ -2253,11 +2255,14 @@ pub const CANONICAL_DECOMP_VAL: [(u32, u32); 2060] = [
     (0x9f3b, 0x0),
     (0x2a600, 0x0),
 ];
-// Warning: script Elymaic not known by HarfBuzz
-// Warning: script Nandinagari not known by HarfBuzz
-// Warning: script Nyiakeng_Puachue_Hmong not known by HarfBuzz
-// Warning: script Wancho not known by HarfBuzz
-pub const SCRIPT_KEY: [(u32, u32); 879] = [
+// Warning: script Vithkuqi not known by HarfBuzz
+// Warning: script Old_Uyghur not known by HarfBuzz
+// Warning: script Kawi not known by HarfBuzz
+// Warning: script Cypro_Minoan not known by HarfBuzz
+// Warning: script Tangsa not known by HarfBuzz
+// Warning: script Toto not known by HarfBuzz
+// Warning: script Nag_Mundari not known by HarfBuzz
+pub static SCRIPT_KEY: [(u32, u32); 935] = [
     (0x0, 0x41),        // Common
     (0x41, 0x5b),       // Latin
     (0x5b, 0x61),       // Common
@@ -2298,9 +2303,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x485, 0x487),     // Inherited
     (0x487, 0x530),     // Cyrillic
     (0x531, 0x557),     // Armenian
-    (0x559, 0x589),     // Armenian
-    (0x589, 0x58a),     // Common
-    (0x58a, 0x58b),     // Armenian
+    (0x559, 0x58b),     // Armenian
     (0x58d, 0x590),     // Armenian
     (0x591, 0x5c8),     // Hebrew
     (0x5d0, 0x5eb),     // Hebrew
@@ -2311,8 +2314,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x60c, 0x60d),     // Common
     (0x60d, 0x61b),     // Arabic
     (0x61b, 0x61c),     // Common
-    (0x61c, 0x61d),     // Arabic
-    (0x61e, 0x61f),     // Arabic
+    (0x61c, 0x61f),     // Arabic
     (0x61f, 0x620),     // Common
     (0x620, 0x640),     // Arabic
     (0x640, 0x641),     // Common
@@ -2335,9 +2337,9 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x840, 0x85c),     // Mandaic
     (0x85e, 0x85f),     // Mandaic
     (0x860, 0x86b),     // Syriac
-    (0x8a0, 0x8b5),     // Arabic
-    (0x8b6, 0x8be),     // Arabic
-    (0x8d3, 0x8e2),     // Arabic
+    (0x870, 0x88f),     // Arabic
+    (0x890, 0x892),     // Arabic
+    (0x898, 0x8e2),     // Arabic
     (0x8e2, 0x8e3),     // Common
     (0x8e3, 0x900),     // Arabic
     (0x900, 0x951),     // Devanagari
@@ -2399,7 +2401,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0xb3c, 0xb45),     // Oriya
     (0xb47, 0xb49),     // Oriya
     (0xb4b, 0xb4e),     // Oriya
-    (0xb56, 0xb58),     // Oriya
+    (0xb55, 0xb58),     // Oriya
     (0xb5c, 0xb5e),     // Oriya
     (0xb5f, 0xb64),     // Oriya
     (0xb66, 0xb78),     // Oriya
@@ -2423,14 +2425,16 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0xc0e, 0xc11),     // Telugu
     (0xc12, 0xc29),     // Telugu
     (0xc2a, 0xc3a),     // Telugu
-    (0xc3d, 0xc45),     // Telugu
+    (0xc3c, 0xc45),     // Telugu
     (0xc46, 0xc49),     // Telugu
     (0xc4a, 0xc4e),     // Telugu
     (0xc55, 0xc57),     // Telugu
     (0xc58, 0xc5b),     // Telugu
+    (0xc5d, 0xc5e),     // Telugu
     (0xc60, 0xc64),     // Telugu
     (0xc66, 0xc70),     // Telugu
-    (0xc77, 0xc8d),     // Telugu
+    (0xc77, 0xc80),     // Telugu
+    (0xc80, 0xc8d),     // Kannada
     (0xc8e, 0xc91),     // Kannada
     (0xc92, 0xca9),     // Kannada
     (0xcaa, 0xcb4),     // Kannada
@@ -2439,19 +2443,18 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0xcc6, 0xcc9),     // Kannada
     (0xcca, 0xcce),     // Kannada
     (0xcd5, 0xcd7),     // Kannada
-    (0xcde, 0xcdf),     // Kannada
+    (0xcdd, 0xcdf),     // Kannada
     (0xce0, 0xce4),     // Kannada
     (0xce6, 0xcf0),     // Kannada
-    (0xcf1, 0xcf3),     // Kannada
-    (0xd00, 0xd04),     // Malayalam
-    (0xd05, 0xd0d),     // Malayalam
+    (0xcf1, 0xcf4),     // Kannada
+    (0xd00, 0xd0d),     // Malayalam
     (0xd0e, 0xd11),     // Malayalam
     (0xd12, 0xd45),     // Malayalam
     (0xd46, 0xd49),     // Malayalam
     (0xd4a, 0xd50),     // Malayalam
     (0xd54, 0xd64),     // Malayalam
     (0xd66, 0xd80),     // Malayalam
-    (0xd82, 0xd84),     // Sinhala
+    (0xd81, 0xd84),     // Sinhala
     (0xd85, 0xd97),     // Sinhala
     (0xd9a, 0xdb2),     // Sinhala
     (0xdb3, 0xdbc),     // Sinhala
@@ -2474,7 +2477,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0xea7, 0xebe),     // Lao
     (0xec0, 0xec5),     // Lao
     (0xec6, 0xec7),     // Lao
-    (0xec8, 0xece),     // Lao
+    (0xec8, 0xecf),     // Lao
     (0xed0, 0xeda),     // Lao
     (0xedc, 0xee0),     // Lao
     (0xf00, 0xf48),     // Tibetan
@@ -2518,8 +2521,8 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x16a0, 0x16eb),   // Runic
     (0x16eb, 0x16ee),   // Common
     (0x16ee, 0x16f9),   // Runic
-    (0x1700, 0x170d),   // Tagalog
-    (0x170e, 0x1715),   // Tagalog
+    (0x1700, 0x1716),   // Tagalog
+    (0x171f, 0x1720),   // Tagalog
     (0x1720, 0x1735),   // Hanunoo
     (0x1735, 0x1737),   // Common
     (0x1740, 0x1754),   // Buhid
@@ -2533,8 +2536,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x1802, 0x1804),   // Common
     (0x1804, 0x1805),   // Mongolian
     (0x1805, 0x1806),   // Common
-    (0x1806, 0x180f),   // Mongolian
-    (0x1810, 0x181a),   // Mongolian
+    (0x1806, 0x181a),   // Mongolian
     (0x1820, 0x1879),   // Mongolian
     (0x1880, 0x18ab),   // Mongolian
     (0x18b0, 0x18f6),   // Canadian_Aboriginal
@@ -2542,7 +2544,8 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x1920, 0x192c),   // Limbu
     (0x1930, 0x193c),   // Limbu
     (0x1940, 0x1941),   // Limbu
-    (0x1944, 0x196e),   // Limbu
+    (0x1944, 0x1950),   // Limbu
+    (0x1950, 0x196e),   // Tai_Le
     (0x1970, 0x1975),   // Tai_Le
     (0x1980, 0x19ac),   // New_Tai_Lue
     (0x19b0, 0x19ca),   // New_Tai_Lue
@@ -2556,15 +2559,16 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x1a7f, 0x1a8a),   // Tai_Tham
     (0x1a90, 0x1a9a),   // Tai_Tham
     (0x1aa0, 0x1aae),   // Tai_Tham
-    (0x1ab0, 0x1abf),   // Inherited
-    (0x1b00, 0x1b4c),   // Balinese
-    (0x1b50, 0x1b7d),   // Balinese
+    (0x1ab0, 0x1acf),   // Inherited
+    (0x1b00, 0x1b4d),   // Balinese
+    (0x1b50, 0x1b7f),   // Balinese
     (0x1b80, 0x1bc0),   // Sundanese
     (0x1bc0, 0x1bf4),   // Batak
     (0x1bfc, 0x1c00),   // Batak
     (0x1c00, 0x1c38),   // Lepcha
     (0x1c3b, 0x1c4a),   // Lepcha
-    (0x1c4d, 0x1c80),   // Lepcha
+    (0x1c4d, 0x1c50),   // Lepcha
+    (0x1c50, 0x1c80),   // Ol_Chiki
     (0x1c80, 0x1c89),   // Cyrillic
     (0x1c90, 0x1cbb),   // Georgian
     (0x1cbd, 0x1cc0),   // Georgian
@@ -2592,8 +2596,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x1d78, 0x1d79),   // Cyrillic
     (0x1d79, 0x1dbf),   // Latin
     (0x1dbf, 0x1dc0),   // Greek
-    (0x1dc0, 0x1dfa),   // Inherited
-    (0x1dfb, 0x1e00),   // Inherited
+    (0x1dc0, 0x1e00),   // Inherited
     (0x1e00, 0x1f00),   // Latin
     (0x1f00, 0x1f16),   // Greek
     (0x1f18, 0x1f1e),   // Greek
@@ -2620,7 +2623,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x207f, 0x2080),   // Latin
     (0x2080, 0x208f),   // Common
     (0x2090, 0x209d),   // Latin
-    (0x20a0, 0x20c0),   // Common
+    (0x20a0, 0x20c1),   // Common
     (0x20d0, 0x20f1),   // Inherited
     (0x2100, 0x2126),   // Common
     (0x2126, 0x2127),   // Greek
@@ -2639,9 +2642,8 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x2800, 0x2900),   // Braille
     (0x2900, 0x2b74),   // Common
     (0x2b76, 0x2b96),   // Common
-    (0x2b98, 0x2c00),   // Common
-    (0x2c00, 0x2c2f),   // Glagolitic
-    (0x2c30, 0x2c5f),   // Glagolitic
+    (0x2b97, 0x2c00),   // Common
+    (0x2c00, 0x2c60),   // Glagolitic
     (0x2c60, 0x2c80),   // Latin
     (0x2c80, 0x2cf4),   // Coptic
     (0x2cf9, 0x2d00),   // Coptic
@@ -2661,7 +2663,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x2dd0, 0x2dd7),   // Ethiopic
     (0x2dd8, 0x2ddf),   // Ethiopic
     (0x2de0, 0x2e00),   // Cyrillic
-    (0x2e00, 0x2e50),   // Common
+    (0x2e00, 0x2e5e),   // Common
     (0x2e80, 0x2e9a),   // Han
     (0x2e9b, 0x2ef4),   // Han
     (0x2f00, 0x2fd6),   // Han
@@ -2688,7 +2690,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x3105, 0x3130),   // Bopomofo
     (0x3131, 0x318f),   // Hangul
     (0x3190, 0x31a0),   // Common
-    (0x31a0, 0x31bb),   // Bopomofo
+    (0x31a0, 0x31c0),   // Bopomofo
     (0x31c0, 0x31e4),   // Common
     (0x31f0, 0x3200),   // Katakana
     (0x3200, 0x321f),   // Hangul
@@ -2696,11 +2698,12 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x3260, 0x327f),   // Hangul
     (0x327f, 0x32d0),   // Common
     (0x32d0, 0x32ff),   // Katakana
+    (0x32ff, 0x3300),   // Common
     (0x3300, 0x3358),   // Katakana
     (0x3358, 0x3400),   // Common
-    (0x3400, 0x4db6),   // Han
+    (0x3400, 0x4dc0),   // Han
     (0x4dc0, 0x4e00),   // Common
-    (0x4e00, 0x9ff0),   // Han
+    (0x4e00, 0xa000),   // Han
     (0xa000, 0xa48d),   // Yi
     (0xa490, 0xa4c7),   // Yi
     (0xa4d0, 0xa500),   // Lisu
@@ -2710,10 +2713,12 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0xa700, 0xa722),   // Common
     (0xa722, 0xa788),   // Latin
     (0xa788, 0xa78b),   // Common
-    (0xa78b, 0xa7c0),   // Latin
-    (0xa7c2, 0xa7c7),   // Latin
-    (0xa7f7, 0xa800),   // Latin
-    (0xa800, 0xa82c),   // Syloti_Nagri
+    (0xa78b, 0xa7cb),   // Latin
+    (0xa7d0, 0xa7d2),   // Latin
+    (0xa7d3, 0xa7d4),   // Latin
+    (0xa7d5, 0xa7da),   // Latin
+    (0xa7f2, 0xa800),   // Latin
+    (0xa800, 0xa82d),   // Syloti_Nagri
     (0xa830, 0xa83a),   // Common
     (0xa840, 0xa878),   // Phags_Pa
     (0xa880, 0xa8c6),   // Saurashtra
@@ -2721,7 +2726,8 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0xa8e0, 0xa900),   // Devanagari
     (0xa900, 0xa92e),   // Kayah_Li
     (0xa92e, 0xa92f),   // Common
-    (0xa92f, 0xa954),   // Kayah_Li
+    (0xa92f, 0xa930),   // Kayah_Li
+    (0xa930, 0xa954),   // Rejang
     (0xa95f, 0xa960),   // Rejang
     (0xa960, 0xa97d),   // Hangul
     (0xa980, 0xa9ce),   // Javanese
@@ -2746,7 +2752,8 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0xab5b, 0xab5c),   // Common
     (0xab5c, 0xab65),   // Latin
     (0xab65, 0xab66),   // Greek
-    (0xab66, 0xab68),   // Latin
+    (0xab66, 0xab6a),   // Latin
+    (0xab6a, 0xab6c),   // Common
     (0xab70, 0xabc0),   // Cherokee
     (0xabc0, 0xabee),   // Meetei_Mayek
     (0xabf0, 0xabfa),   // Meetei_Mayek
@@ -2763,12 +2770,13 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0xfb40, 0xfb42),   // Hebrew
     (0xfb43, 0xfb45),   // Hebrew
     (0xfb46, 0xfb50),   // Hebrew
-    (0xfb50, 0xfbc2),   // Arabic
+    (0xfb50, 0xfbc3),   // Arabic
     (0xfbd3, 0xfd3e),   // Arabic
     (0xfd3e, 0xfd40),   // Common
-    (0xfd50, 0xfd90),   // Arabic
+    (0xfd40, 0xfd90),   // Arabic
     (0xfd92, 0xfdc8),   // Arabic
-    (0xfdf0, 0xfdfe),   // Arabic
+    (0xfdcf, 0xfdd0),   // Arabic
+    (0xfdf0, 0xfe00),   // Arabic
     (0xfe00, 0xfe10),   // Inherited
     (0xfe10, 0xfe1a),   // Common
     (0xfe20, 0xfe2e),   // Inherited
@@ -2807,7 +2815,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x10107, 0x10134), // Common
     (0x10137, 0x10140), // Common
     (0x10140, 0x1018f), // Greek
-    (0x10190, 0x1019c), // Common
+    (0x10190, 0x1019d), // Common
     (0x101a0, 0x101a1), // Greek
     (0x101d0, 0x101fd), // Common
     (0x101fd, 0x101fe), // Inherited
@@ -2816,14 +2824,16 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x102e0, 0x102e1), // Inherited
     (0x102e1, 0x102fc), // Common
     (0x10300, 0x10324), // Old_Italic
-    (0x1032d, 0x1034b), // Old_Italic
+    (0x1032d, 0x10330), // Old_Italic
+    (0x10330, 0x1034b), // Gothic
     (0x10350, 0x1037b), // Old_Permic
     (0x10380, 0x1039e), // Ugaritic
     (0x1039f, 0x103a0), // Ugaritic
     (0x103a0, 0x103c4), // Old_Persian
     (0x103c8, 0x103d6), // Old_Persian
     (0x10400, 0x10450), // Deseret
-    (0x10450, 0x1049e), // Shavian
+    (0x10450, 0x10480), // Shavian
+    (0x10480, 0x1049e), // Osmanya
     (0x104a0, 0x104aa), // Osmanya
     (0x104b0, 0x104d4), // Osage
     (0x104d8, 0x104fc), // Osage
@@ -2833,6 +2843,9 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x10600, 0x10737), // Linear_A
     (0x10740, 0x10756), // Linear_A
     (0x10760, 0x10768), // Linear_A
+    (0x10780, 0x10786), // Latin
+    (0x10787, 0x107b1), // Latin
+    (0x107b2, 0x107bb), // Latin
     (0x10800, 0x10806), // Cypriot
     (0x10808, 0x10809), // Cypriot
     (0x1080a, 0x10836), // Cypriot
@@ -2870,7 +2883,8 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x10b00, 0x10b36), // Avestan
     (0x10b39, 0x10b40), // Avestan
     (0x10b40, 0x10b56), // Inscriptional_Parthian
-    (0x10b58, 0x10b73), // Inscriptional_Parthian
+    (0x10b58, 0x10b60), // Inscriptional_Parthian
+    (0x10b60, 0x10b73), // Inscriptional_Pahlavi
     (0x10b78, 0x10b80), // Inscriptional_Pahlavi
     (0x10b80, 0x10b92), // Psalter_Pahlavi
     (0x10b99, 0x10b9d), // Psalter_Pahlavi
@@ -2882,23 +2896,28 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x10d00, 0x10d28), // Hanifi_Rohingya
     (0x10d30, 0x10d3a), // Hanifi_Rohingya
     (0x10e60, 0x10e7f), // Arabic
+    (0x10e80, 0x10eaa), // Yezidi
+    (0x10eab, 0x10eae), // Yezidi
+    (0x10eb0, 0x10eb2), // Yezidi
+    (0x10efd, 0x10f00), // Arabic
     (0x10f00, 0x10f28), // Old_Sogdian
     (0x10f30, 0x10f5a), // Sogdian
+    (0x10fb0, 0x10fcc), // Chorasmian
+    (0x10fe0, 0x10ff7), // Elymaic
     (0x11000, 0x1104e), // Brahmi
-    (0x11052, 0x11070), // Brahmi
+    (0x11052, 0x11076), // Brahmi
     (0x1107f, 0x11080), // Brahmi
-    (0x11080, 0x110c2), // Kaithi
+    (0x11080, 0x110c3), // Kaithi
     (0x110cd, 0x110ce), // Kaithi
     (0x110d0, 0x110e9), // Sora_Sompeng
     (0x110f0, 0x110fa), // Sora_Sompeng
     (0x11100, 0x11135), // Chakma
-    (0x11136, 0x11147), // Chakma
+    (0x11136, 0x11148), // Chakma
     (0x11150, 0x11177), // Mahajani
-    (0x11180, 0x111ce), // Sharada
-    (0x111d0, 0x111e0), // Sharada
+    (0x11180, 0x111e0), // Sharada
     (0x111e1, 0x111f5), // Sinhala
     (0x11200, 0x11212), // Khojki
-    (0x11213, 0x1123f), // Khojki
+    (0x11213, 0x11242), // Khojki
     (0x11280, 0x11287), // Multani
     (0x11288, 0x11289), // Multani
     (0x1128a, 0x1128e), // Multani
@@ -2922,9 +2941,8 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x1135d, 0x11364), // Grantha
     (0x11366, 0x1136d), // Grantha
     (0x11370, 0x11375), // Grantha
-    (0x11400, 0x1145a), // Newa
-    (0x1145b, 0x1145c), // Newa
-    (0x1145d, 0x11460), // Newa
+    (0x11400, 0x1145c), // Newa
+    (0x1145d, 0x11462), // Newa
     (0x11480, 0x114c8), // Tirhuta
     (0x114d0, 0x114da), // Tirhuta
     (0x11580, 0x115b6), // Siddham
@@ -2932,17 +2950,30 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x11600, 0x11645), // Modi
     (0x11650, 0x1165a), // Modi
     (0x11660, 0x1166d), // Mongolian
-    (0x11680, 0x116b9), // Takri
+    (0x11680, 0x116ba), // Takri
     (0x116c0, 0x116ca), // Takri
     (0x11700, 0x1171b), // Ahom
     (0x1171d, 0x1172c), // Ahom
-    (0x11730, 0x11740), // Ahom
+    (0x11730, 0x11747), // Ahom
     (0x11800, 0x1183c), // Dogra
     (0x118a0, 0x118f3), // Warang_Citi
     (0x118ff, 0x11900), // Warang_Citi
+    (0x11900, 0x11907), // Dives_Akuru
+    (0x11909, 0x1190a), // Dives_Akuru
+    (0x1190c, 0x11914), // Dives_Akuru
+    (0x11915, 0x11917), // Dives_Akuru
+    (0x11918, 0x11936), // Dives_Akuru
+    (0x11937, 0x11939), // Dives_Akuru
+    (0x1193b, 0x11947), // Dives_Akuru
+    (0x11950, 0x1195a), // Dives_Akuru
+    (0x119a0, 0x119a8), // Nandinagari
+    (0x119aa, 0x119d8), // Nandinagari
+    (0x119da, 0x119e5), // Nandinagari
     (0x11a00, 0x11a48), // Zanabazar_Square
     (0x11a50, 0x11aa3), // Soyombo
+    (0x11ab0, 0x11ac0), // Canadian_Aboriginal
     (0x11ac0, 0x11af9), // Pau_Cin_Hau
+    (0x11b00, 0x11b0a), // Devanagari
     (0x11c00, 0x11c09), // Bhaiksuki
     (0x11c0a, 0x11c37), // Bhaiksuki
     (0x11c38, 0x11c46), // Bhaiksuki
@@ -2964,14 +2995,14 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x11d93, 0x11d99), // Gunjala_Gondi
     (0x11da0, 0x11daa), // Gunjala_Gondi
     (0x11ee0, 0x11ef9), // Makasar
+    (0x11fb0, 0x11fb1), // Lisu
     (0x11fc0, 0x11ff2), // Tamil
     (0x11fff, 0x12000), // Tamil
     (0x12000, 0x1239a), // Cuneiform
     (0x12400, 0x1246f), // Cuneiform
     (0x12470, 0x12475), // Cuneiform
     (0x12480, 0x12544), // Cuneiform
-    (0x13000, 0x1342f), // Egyptian_Hieroglyphs
-    (0x13430, 0x13439), // Egyptian_Hieroglyphs
+    (0x13000, 0x13456), // Egyptian_Hieroglyphs
     (0x14400, 0x14647), // Anatolian_Hieroglyphs
     (0x16800, 0x16a39), // Bamum
     (0x16a40, 0x16a5f), // Mro
@@ -2990,12 +3021,22 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x16f8f, 0x16fa0), // Miao
     (0x16fe0, 0x16fe1), // Tangut
     (0x16fe1, 0x16fe2), // Nushu
-    (0x16fe2, 0x16fe4), // Common
+    (0x16fe2, 0x16fe4), // Han
+    (0x16fe4, 0x16fe5), // Khitan_Small_Script
+    (0x16ff0, 0x16ff2), // Han
     (0x17000, 0x187f8), // Tangut
-    (0x18800, 0x18af3), // Tangut
+    (0x18800, 0x18b00), // Tangut
+    (0x18b00, 0x18cd6), // Khitan_Small_Script
+    (0x18d00, 0x18d09), // Tangut
+    (0x1aff0, 0x1aff4), // Katakana
+    (0x1aff5, 0x1affc), // Katakana
+    (0x1affd, 0x1afff), // Katakana
     (0x1b000, 0x1b001), // Katakana
-    (0x1b001, 0x1b11f), // Hiragana
+    (0x1b001, 0x1b120), // Hiragana
+    (0x1b120, 0x1b123), // Katakana
+    (0x1b132, 0x1b133), // Hiragana
     (0x1b150, 0x1b153), // Hiragana
+    (0x1b155, 0x1b156), // Katakana
     (0x1b164, 0x1b168), // Katakana
     (0x1b170, 0x1b2fc), // Nushu
     (0x1bc00, 0x1bc6b), // Duployan
@@ -3004,6 +3045,9 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x1bc90, 0x1bc9a), // Duployan
     (0x1bc9c, 0x1bca0), // Duployan
     (0x1bca0, 0x1bca4), // Common
+    (0x1cf00, 0x1cf2e), // Inherited
+    (0x1cf30, 0x1cf47), // Inherited
+    (0x1cf50, 0x1cfc4), // Common
     (0x1d000, 0x1d0f6), // Common
     (0x1d100, 0x1d127), // Common
     (0x1d129, 0x1d167), // Common
@@ -3014,8 +3058,9 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x1d185, 0x1d18c), // Inherited
     (0x1d18c, 0x1d1aa), // Common
     (0x1d1aa, 0x1d1ae), // Inherited
-    (0x1d1ae, 0x1d1e9), // Common
+    (0x1d1ae, 0x1d1eb), // Common
     (0x1d200, 0x1d246), // Greek
+    (0x1d2c0, 0x1d2d4), // Common
     (0x1d2e0, 0x1d2f4), // Common
     (0x1d300, 0x1d357), // Common
     (0x1d360, 0x1d379), // Common
@@ -3043,11 +3088,25 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x1d800, 0x1da8c), // SignWriting
     (0x1da9b, 0x1daa0), // SignWriting
     (0x1daa1, 0x1dab0), // SignWriting
+    (0x1df00, 0x1df1f), // Latin
+    (0x1df25, 0x1df2b), // Latin
     (0x1e000, 0x1e007), // Glagolitic
     (0x1e008, 0x1e019), // Glagolitic
     (0x1e01b, 0x1e022), // Glagolitic
     (0x1e023, 0x1e025), // Glagolitic
     (0x1e026, 0x1e02b), // Glagolitic
+    (0x1e030, 0x1e06e), // Cyrillic
+    (0x1e08f, 0x1e090), // Cyrillic
+    (0x1e100, 0x1e12d), // Nyiakeng_Puachue_Hmong
+    (0x1e130, 0x1e13e), // Nyiakeng_Puachue_Hmong
+    (0x1e140, 0x1e14a), // Nyiakeng_Puachue_Hmong
+    (0x1e14e, 0x1e150), // Nyiakeng_Puachue_Hmong
+    (0x1e2c0, 0x1e2fa), // Wancho
+    (0x1e2ff, 0x1e300), // Wancho
+    (0x1e7e0, 0x1e7e7), // Ethiopic
+    (0x1e7e8, 0x1e7ec), // Ethiopic
+    (0x1e7ed, 0x1e7ef), // Ethiopic
+    (0x1e7f0, 0x1e7ff), // Ethiopic
     (0x1e800, 0x1e8c5), // Mende_Kikakui
     (0x1e8c7, 0x1e8d7), // Mende_Kikakui
     (0x1e900, 0x1e94c), // Adlam
@@ -3095,9 +3154,7 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x1f0b1, 0x1f0c0), // Common
     (0x1f0c1, 0x1f0d0), // Common
     (0x1f0d1, 0x1f0f6), // Common
-    (0x1f100, 0x1f10d), // Common
-    (0x1f110, 0x1f16d), // Common
-    (0x1f170, 0x1f1ad), // Common
+    (0x1f100, 0x1f1ae), // Common
     (0x1f1e6, 0x1f200), // Common
     (0x1f200, 0x1f201), // Hiragana
     (0x1f201, 0x1f203), // Common
@@ -3105,921 +3162,1713 @@ pub const SCRIPT_KEY: [(u32, u32); 879] = [
     (0x1f240, 0x1f249), // Common
     (0x1f250, 0x1f252), // Common
     (0x1f260, 0x1f266), // Common
-    (0x1f300, 0x1f6d6), // Common
-    (0x1f6e0, 0x1f6ed), // Common
-    (0x1f6f0, 0x1f6fb), // Common
-    (0x1f700, 0x1f774), // Common
-    (0x1f780, 0x1f7d9), // Common
+    (0x1f300, 0x1f6d8), // Common
+    (0x1f6dc, 0x1f6ed), // Common
+    (0x1f6f0, 0x1f6fd), // Common
+    (0x1f700, 0x1f777), // Common
+    (0x1f77b, 0x1f7da), // Common
     (0x1f7e0, 0x1f7ec), // Common
+    (0x1f7f0, 0x1f7f1), // Common
     (0x1f800, 0x1f80c), // Common
     (0x1f810, 0x1f848), // Common
     (0x1f850, 0x1f85a), // Common
     (0x1f860, 0x1f888), // Common
     (0x1f890, 0x1f8ae), // Common
-    (0x1f900, 0x1f90c), // Common
-    (0x1f90d, 0x1f972), // Common
-    (0x1f973, 0x1f977), // Common
-    (0x1f97a, 0x1f9a3), // Common
-    (0x1f9a5, 0x1f9ab), // Common
-    (0x1f9ae, 0x1f9cb), // Common
-    (0x1f9cd, 0x1fa54), // Common
+    (0x1f8b0, 0x1f8b2), // Common
+    (0x1f900, 0x1fa54), // Common
     (0x1fa60, 0x1fa6e), // Common
-    (0x1fa70, 0x1fa74), // Common
-    (0x1fa78, 0x1fa7b), // Common
-    (0x1fa80, 0x1fa83), // Common
-    (0x1fa90, 0x1fa96), // Common
-    (0x20000, 0x2a6d7), // Han
-    (0x2a700, 0x2b735), // Han
+    (0x1fa70, 0x1fa7d), // Common
+    (0x1fa80, 0x1fa89), // Common
+    (0x1fa90, 0x1fabe), // Common
+    (0x1fabf, 0x1fac6), // Common
+    (0x1face, 0x1fadc), // Common
+    (0x1fae0, 0x1fae9), // Common
+    (0x1faf0, 0x1faf9), // Common
+    (0x1fb00, 0x1fb93), // Common
+    (0x1fb94, 0x1fbcb), // Common
+    (0x1fbf0, 0x1fbfa), // Common
+    (0x20000, 0x2a6e0), // Han
+    (0x2a700, 0x2b73a), // Han
     (0x2b740, 0x2b81e), // Han
     (0x2b820, 0x2cea2), // Han
     (0x2ceb0, 0x2ebe1), // Han
     (0x2f800, 0x2fa1e), // Han
+    (0x30000, 0x3134b), // Han
+    (0x31350, 0x323b0), // Han
     (0xe0001, 0xe0002), // Common
     (0xe0020, 0xe0080), // Common
     (0xe0100, 0xe01f0), // Inherited
 ];
-pub const SCRIPT_VAL: [hb_script_t; 879] = [
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_BOPOMOFO,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_COPTIC,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_CYRILLIC,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_CYRILLIC,
-    HB_SCRIPT_ARMENIAN,
-    HB_SCRIPT_ARMENIAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARMENIAN,
-    HB_SCRIPT_ARMENIAN,
-    HB_SCRIPT_HEBREW,
-    HB_SCRIPT_HEBREW,
-    HB_SCRIPT_HEBREW,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_SYRIAC,
-    HB_SCRIPT_SYRIAC,
-    HB_SCRIPT_SYRIAC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_THAANA,
-    HB_SCRIPT_NKO,
-    HB_SCRIPT_NKO,
-    HB_SCRIPT_SAMARITAN,
-    HB_SCRIPT_SAMARITAN,
-    HB_SCRIPT_MANDAIC,
-    HB_SCRIPT_MANDAIC,
-    HB_SCRIPT_SYRIAC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_DEVANAGARI,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_DEVANAGARI,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_DEVANAGARI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_BENGALI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GURMUKHI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_GUJARATI,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_ORIYA,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_TELUGU,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_KANNADA,
-    HB_SCRIPT_MALAYALAM,
-    HB_SCRIPT_MALAYALAM,
-    HB_SCRIPT_MALAYALAM,
-    HB_SCRIPT_MALAYALAM,
-    HB_SCRIPT_MALAYALAM,
-    HB_SCRIPT_MALAYALAM,
-    HB_SCRIPT_MALAYALAM,
-    HB_SCRIPT_MALAYALAM,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_THAI,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_THAI,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_LAO,
-    HB_SCRIPT_TIBETAN,
-    HB_SCRIPT_TIBETAN,
-    HB_SCRIPT_TIBETAN,
-    HB_SCRIPT_TIBETAN,
-    HB_SCRIPT_TIBETAN,
-    HB_SCRIPT_TIBETAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_TIBETAN,
-    HB_SCRIPT_MYANMAR,
-    HB_SCRIPT_GEORGIAN,
-    HB_SCRIPT_GEORGIAN,
-    HB_SCRIPT_GEORGIAN,
-    HB_SCRIPT_GEORGIAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_GEORGIAN,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_CHEROKEE,
-    HB_SCRIPT_CHEROKEE,
-    HB_SCRIPT_CANADIAN_SYLLABICS,
-    HB_SCRIPT_OGHAM,
-    HB_SCRIPT_RUNIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_RUNIC,
-    HB_SCRIPT_TAGALOG,
-    HB_SCRIPT_TAGALOG,
-    HB_SCRIPT_HANUNOO,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_BUHID,
-    HB_SCRIPT_TAGBANWA,
-    HB_SCRIPT_TAGBANWA,
-    HB_SCRIPT_TAGBANWA,
-    HB_SCRIPT_KHMER,
-    HB_SCRIPT_KHMER,
-    HB_SCRIPT_KHMER,
-    HB_SCRIPT_MONGOLIAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_MONGOLIAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_MONGOLIAN,
-    HB_SCRIPT_MONGOLIAN,
-    HB_SCRIPT_MONGOLIAN,
-    HB_SCRIPT_MONGOLIAN,
-    HB_SCRIPT_CANADIAN_SYLLABICS,
-    HB_SCRIPT_LIMBU,
-    HB_SCRIPT_LIMBU,
-    HB_SCRIPT_LIMBU,
-    HB_SCRIPT_LIMBU,
-    HB_SCRIPT_LIMBU,
-    HB_SCRIPT_TAI_LE,
-    HB_SCRIPT_NEW_TAI_LUE,
-    HB_SCRIPT_NEW_TAI_LUE,
-    HB_SCRIPT_NEW_TAI_LUE,
-    HB_SCRIPT_NEW_TAI_LUE,
-    HB_SCRIPT_KHMER,
-    HB_SCRIPT_BUGINESE,
-    HB_SCRIPT_BUGINESE,
-    HB_SCRIPT_TAI_THAM,
-    HB_SCRIPT_TAI_THAM,
-    HB_SCRIPT_TAI_THAM,
-    HB_SCRIPT_TAI_THAM,
-    HB_SCRIPT_TAI_THAM,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_BALINESE,
-    HB_SCRIPT_BALINESE,
-    HB_SCRIPT_SUNDANESE,
-    HB_SCRIPT_BATAK,
-    HB_SCRIPT_BATAK,
-    HB_SCRIPT_LEPCHA,
-    HB_SCRIPT_LEPCHA,
-    HB_SCRIPT_LEPCHA,
-    HB_SCRIPT_CYRILLIC,
-    HB_SCRIPT_GEORGIAN,
-    HB_SCRIPT_GEORGIAN,
-    HB_SCRIPT_SUNDANESE,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_CYRILLIC,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_CYRILLIC,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_BRAILLE,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_GLAGOLITIC,
-    HB_SCRIPT_GLAGOLITIC,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COPTIC,
-    HB_SCRIPT_COPTIC,
-    HB_SCRIPT_GEORGIAN,
-    HB_SCRIPT_GEORGIAN,
-    HB_SCRIPT_GEORGIAN,
-    HB_SCRIPT_TIFINAGH,
-    HB_SCRIPT_TIFINAGH,
-    HB_SCRIPT_TIFINAGH,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_CYRILLIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HIRAGANA,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HIRAGANA,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_KATAKANA,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_KATAKANA,
-    HB_SCRIPT_BOPOMOFO,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_BOPOMOFO,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_KATAKANA,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_KATAKANA,
-    HB_SCRIPT_KATAKANA,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_YI,
-    HB_SCRIPT_YI,
-    HB_SCRIPT_LISU,
-    HB_SCRIPT_VAI,
-    HB_SCRIPT_CYRILLIC,
-    HB_SCRIPT_BAMUM,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_SYLOTI_NAGRI,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_PHAGS_PA,
-    HB_SCRIPT_SAURASHTRA,
-    HB_SCRIPT_SAURASHTRA,
-    HB_SCRIPT_DEVANAGARI,
-    HB_SCRIPT_KAYAH_LI,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_KAYAH_LI,
-    HB_SCRIPT_REJANG,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_JAVANESE,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_JAVANESE,
-    HB_SCRIPT_JAVANESE,
-    HB_SCRIPT_MYANMAR,
-    HB_SCRIPT_CHAM,
-    HB_SCRIPT_CHAM,
-    HB_SCRIPT_CHAM,
-    HB_SCRIPT_CHAM,
-    HB_SCRIPT_MYANMAR,
-    HB_SCRIPT_TAI_VIET,
-    HB_SCRIPT_TAI_VIET,
-    HB_SCRIPT_MEETEI_MAYEK,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_ETHIOPIC,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_CHEROKEE,
-    HB_SCRIPT_MEETEI_MAYEK,
-    HB_SCRIPT_MEETEI_MAYEK,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_ARMENIAN,
-    HB_SCRIPT_HEBREW,
-    HB_SCRIPT_HEBREW,
-    HB_SCRIPT_HEBREW,
-    HB_SCRIPT_HEBREW,
-    HB_SCRIPT_HEBREW,
-    HB_SCRIPT_HEBREW,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_CYRILLIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LATIN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_KATAKANA,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_KATAKANA,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_HANGUL,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_LINEAR_B,
-    HB_SCRIPT_LINEAR_B,
-    HB_SCRIPT_LINEAR_B,
-    HB_SCRIPT_LINEAR_B,
-    HB_SCRIPT_LINEAR_B,
-    HB_SCRIPT_LINEAR_B,
-    HB_SCRIPT_LINEAR_B,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_LYCIAN,
-    HB_SCRIPT_CARIAN,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_OLD_ITALIC,
-    HB_SCRIPT_OLD_ITALIC,
-    HB_SCRIPT_OLD_PERMIC,
-    HB_SCRIPT_UGARITIC,
-    HB_SCRIPT_UGARITIC,
-    HB_SCRIPT_OLD_PERSIAN,
-    HB_SCRIPT_OLD_PERSIAN,
-    HB_SCRIPT_DESERET,
-    HB_SCRIPT_SHAVIAN,
-    HB_SCRIPT_OSMANYA,
-    HB_SCRIPT_OSAGE,
-    HB_SCRIPT_OSAGE,
-    HB_SCRIPT_ELBASAN,
-    HB_SCRIPT_CAUCASIAN_ALBANIAN,
-    HB_SCRIPT_CAUCASIAN_ALBANIAN,
-    HB_SCRIPT_LINEAR_A,
-    HB_SCRIPT_LINEAR_A,
-    HB_SCRIPT_LINEAR_A,
-    HB_SCRIPT_CYPRIOT,
-    HB_SCRIPT_CYPRIOT,
-    HB_SCRIPT_CYPRIOT,
-    HB_SCRIPT_CYPRIOT,
-    HB_SCRIPT_CYPRIOT,
-    HB_SCRIPT_CYPRIOT,
-    HB_SCRIPT_IMPERIAL_ARAMAIC,
-    HB_SCRIPT_IMPERIAL_ARAMAIC,
-    HB_SCRIPT_PALMYRENE,
-    HB_SCRIPT_NABATAEAN,
-    HB_SCRIPT_NABATAEAN,
-    HB_SCRIPT_HATRAN,
-    HB_SCRIPT_HATRAN,
-    HB_SCRIPT_HATRAN,
-    HB_SCRIPT_PHOENICIAN,
-    HB_SCRIPT_PHOENICIAN,
-    HB_SCRIPT_LYDIAN,
-    HB_SCRIPT_LYDIAN,
-    HB_SCRIPT_MEROITIC_HIEROGLYPHS,
-    HB_SCRIPT_MEROITIC_CURSIVE,
-    HB_SCRIPT_MEROITIC_CURSIVE,
-    HB_SCRIPT_MEROITIC_CURSIVE,
-    HB_SCRIPT_KHAROSHTHI,
-    HB_SCRIPT_KHAROSHTHI,
-    HB_SCRIPT_KHAROSHTHI,
-    HB_SCRIPT_KHAROSHTHI,
-    HB_SCRIPT_KHAROSHTHI,
-    HB_SCRIPT_KHAROSHTHI,
-    HB_SCRIPT_KHAROSHTHI,
-    HB_SCRIPT_KHAROSHTHI,
-    HB_SCRIPT_OLD_SOUTH_ARABIAN,
-    HB_SCRIPT_OLD_NORTH_ARABIAN,
-    HB_SCRIPT_MANICHAEAN,
-    HB_SCRIPT_MANICHAEAN,
-    HB_SCRIPT_AVESTAN,
-    HB_SCRIPT_AVESTAN,
-    HB_SCRIPT_INSCRIPTIONAL_PARTHIAN,
-    HB_SCRIPT_INSCRIPTIONAL_PARTHIAN,
-    HB_SCRIPT_INSCRIPTIONAL_PAHLAVI,
-    HB_SCRIPT_PSALTER_PAHLAVI,
-    HB_SCRIPT_PSALTER_PAHLAVI,
-    HB_SCRIPT_PSALTER_PAHLAVI,
-    HB_SCRIPT_OLD_TURKIC,
-    HB_SCRIPT_OLD_HUNGARIAN,
-    HB_SCRIPT_OLD_HUNGARIAN,
-    HB_SCRIPT_OLD_HUNGARIAN,
-    HB_SCRIPT_HANIFI_ROHINGYA,
-    HB_SCRIPT_HANIFI_ROHINGYA,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_OLD_SOGDIAN,
-    HB_SCRIPT_SOGDIAN,
-    HB_SCRIPT_BRAHMI,
-    HB_SCRIPT_BRAHMI,
-    HB_SCRIPT_BRAHMI,
-    HB_SCRIPT_KAITHI,
-    HB_SCRIPT_KAITHI,
-    HB_SCRIPT_SORA_SOMPENG,
-    HB_SCRIPT_SORA_SOMPENG,
-    HB_SCRIPT_CHAKMA,
-    HB_SCRIPT_CHAKMA,
-    HB_SCRIPT_MAHAJANI,
-    HB_SCRIPT_SHARADA,
-    HB_SCRIPT_SHARADA,
-    HB_SCRIPT_SINHALA,
-    HB_SCRIPT_KHOJKI,
-    HB_SCRIPT_KHOJKI,
-    HB_SCRIPT_MULTANI,
-    HB_SCRIPT_MULTANI,
-    HB_SCRIPT_MULTANI,
-    HB_SCRIPT_MULTANI,
-    HB_SCRIPT_MULTANI,
-    HB_SCRIPT_KHUDAWADI,
-    HB_SCRIPT_KHUDAWADI,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_GRANTHA,
-    HB_SCRIPT_NEWA,
-    HB_SCRIPT_NEWA,
-    HB_SCRIPT_NEWA,
-    HB_SCRIPT_TIRHUTA,
-    HB_SCRIPT_TIRHUTA,
-    HB_SCRIPT_SIDDHAM,
-    HB_SCRIPT_SIDDHAM,
-    HB_SCRIPT_MODI,
-    HB_SCRIPT_MODI,
-    HB_SCRIPT_MONGOLIAN,
-    HB_SCRIPT_TAKRI,
-    HB_SCRIPT_TAKRI,
-    HB_SCRIPT_AHOM,
-    HB_SCRIPT_AHOM,
-    HB_SCRIPT_AHOM,
-    HB_SCRIPT_DOGRA,
-    HB_SCRIPT_WARANG_CITI,
-    HB_SCRIPT_WARANG_CITI,
-    HB_SCRIPT_ZANABAZAR_SQUARE,
-    HB_SCRIPT_SOYOMBO,
-    HB_SCRIPT_PAU_CIN_HAU,
-    HB_SCRIPT_BHAIKSUKI,
-    HB_SCRIPT_BHAIKSUKI,
-    HB_SCRIPT_BHAIKSUKI,
-    HB_SCRIPT_BHAIKSUKI,
-    HB_SCRIPT_MARCHEN,
-    HB_SCRIPT_MARCHEN,
-    HB_SCRIPT_MARCHEN,
-    HB_SCRIPT_MASARAM_GONDI,
-    HB_SCRIPT_MASARAM_GONDI,
-    HB_SCRIPT_MASARAM_GONDI,
-    HB_SCRIPT_MASARAM_GONDI,
-    HB_SCRIPT_MASARAM_GONDI,
-    HB_SCRIPT_MASARAM_GONDI,
-    HB_SCRIPT_MASARAM_GONDI,
-    HB_SCRIPT_GUNJALA_GONDI,
-    HB_SCRIPT_GUNJALA_GONDI,
-    HB_SCRIPT_GUNJALA_GONDI,
-    HB_SCRIPT_GUNJALA_GONDI,
-    HB_SCRIPT_GUNJALA_GONDI,
-    HB_SCRIPT_GUNJALA_GONDI,
-    HB_SCRIPT_MAKASAR,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_TAMIL,
-    HB_SCRIPT_CUNEIFORM,
-    HB_SCRIPT_CUNEIFORM,
-    HB_SCRIPT_CUNEIFORM,
-    HB_SCRIPT_CUNEIFORM,
-    HB_SCRIPT_EGYPTIAN_HIEROGLYPHS,
-    HB_SCRIPT_EGYPTIAN_HIEROGLYPHS,
-    HB_SCRIPT_ANATOLIAN_HIEROGLYPHS,
-    HB_SCRIPT_BAMUM,
-    HB_SCRIPT_MRO,
-    HB_SCRIPT_MRO,
-    HB_SCRIPT_MRO,
-    HB_SCRIPT_BASSA_VAH,
-    HB_SCRIPT_BASSA_VAH,
-    HB_SCRIPT_PAHAWH_HMONG,
-    HB_SCRIPT_PAHAWH_HMONG,
-    HB_SCRIPT_PAHAWH_HMONG,
-    HB_SCRIPT_PAHAWH_HMONG,
-    HB_SCRIPT_PAHAWH_HMONG,
-    HB_SCRIPT_MEDEFAIDRIN,
-    HB_SCRIPT_MIAO,
-    HB_SCRIPT_MIAO,
-    HB_SCRIPT_MIAO,
-    HB_SCRIPT_TANGUT,
-    HB_SCRIPT_NUSHU,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_TANGUT,
-    HB_SCRIPT_TANGUT,
-    HB_SCRIPT_KATAKANA,
-    HB_SCRIPT_HIRAGANA,
-    HB_SCRIPT_HIRAGANA,
-    HB_SCRIPT_KATAKANA,
-    HB_SCRIPT_NUSHU,
-    HB_SCRIPT_DUPLOYAN,
-    HB_SCRIPT_DUPLOYAN,
-    HB_SCRIPT_DUPLOYAN,
-    HB_SCRIPT_DUPLOYAN,
-    HB_SCRIPT_DUPLOYAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_GREEK,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_SIGNWRITING,
-    HB_SCRIPT_SIGNWRITING,
-    HB_SCRIPT_SIGNWRITING,
-    HB_SCRIPT_GLAGOLITIC,
-    HB_SCRIPT_GLAGOLITIC,
-    HB_SCRIPT_GLAGOLITIC,
-    HB_SCRIPT_GLAGOLITIC,
-    HB_SCRIPT_GLAGOLITIC,
-    HB_SCRIPT_MENDE_KIKAKUI,
-    HB_SCRIPT_MENDE_KIKAKUI,
-    HB_SCRIPT_ADLAM,
-    HB_SCRIPT_ADLAM,
-    HB_SCRIPT_ADLAM,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_ARABIC,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HIRAGANA,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_HAN,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_COMMON,
-    HB_SCRIPT_INHERITED,
+pub static SCRIPT_VAL: [Script; 935] = [
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_BOPOMOFO,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_GREEK,
+    SCRIPT_COMMON,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_COMMON,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_COMMON,
+    SCRIPT_GREEK,
+    SCRIPT_COMMON,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_COPTIC,
+    SCRIPT_GREEK,
+    SCRIPT_CYRILLIC,
+    SCRIPT_INHERITED,
+    SCRIPT_CYRILLIC,
+    SCRIPT_ARMENIAN,
+    SCRIPT_ARMENIAN,
+    SCRIPT_ARMENIAN,
+    SCRIPT_HEBREW,
+    SCRIPT_HEBREW,
+    SCRIPT_HEBREW,
+    SCRIPT_ARABIC,
+    SCRIPT_COMMON,
+    SCRIPT_ARABIC,
+    SCRIPT_COMMON,
+    SCRIPT_ARABIC,
+    SCRIPT_COMMON,
+    SCRIPT_ARABIC,
+    SCRIPT_COMMON,
+    SCRIPT_ARABIC,
+    SCRIPT_COMMON,
+    SCRIPT_ARABIC,
+    SCRIPT_INHERITED,
+    SCRIPT_ARABIC,
+    SCRIPT_INHERITED,
+    SCRIPT_ARABIC,
+    SCRIPT_COMMON,
+    SCRIPT_ARABIC,
+    SCRIPT_SYRIAC,
+    SCRIPT_SYRIAC,
+    SCRIPT_SYRIAC,
+    SCRIPT_ARABIC,
+    SCRIPT_THAANA,
+    SCRIPT_NKO,
+    SCRIPT_NKO,
+    SCRIPT_SAMARITAN,
+    SCRIPT_SAMARITAN,
+    SCRIPT_MANDAIC,
+    SCRIPT_MANDAIC,
+    SCRIPT_SYRIAC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_COMMON,
+    SCRIPT_ARABIC,
+    SCRIPT_DEVANAGARI,
+    SCRIPT_INHERITED,
+    SCRIPT_DEVANAGARI,
+    SCRIPT_COMMON,
+    SCRIPT_DEVANAGARI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_BENGALI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GURMUKHI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_GUJARATI,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_ORIYA,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_TELUGU,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_KANNADA,
+    SCRIPT_MALAYALAM,
+    SCRIPT_MALAYALAM,
+    SCRIPT_MALAYALAM,
+    SCRIPT_MALAYALAM,
+    SCRIPT_MALAYALAM,
+    SCRIPT_MALAYALAM,
+    SCRIPT_MALAYALAM,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_SINHALA,
+    SCRIPT_THAI,
+    SCRIPT_COMMON,
+    SCRIPT_THAI,
+    SCRIPT_LAO,
+    SCRIPT_LAO,
+    SCRIPT_LAO,
+    SCRIPT_LAO,
+    SCRIPT_LAO,
+    SCRIPT_LAO,
+    SCRIPT_LAO,
+    SCRIPT_LAO,
+    SCRIPT_LAO,
+    SCRIPT_LAO,
+    SCRIPT_LAO,
+    SCRIPT_TIBETAN,
+    SCRIPT_TIBETAN,
+    SCRIPT_TIBETAN,
+    SCRIPT_TIBETAN,
+    SCRIPT_TIBETAN,
+    SCRIPT_TIBETAN,
+    SCRIPT_COMMON,
+    SCRIPT_TIBETAN,
+    SCRIPT_MYANMAR,
+    SCRIPT_GEORGIAN,
+    SCRIPT_GEORGIAN,
+    SCRIPT_GEORGIAN,
+    SCRIPT_GEORGIAN,
+    SCRIPT_COMMON,
+    SCRIPT_GEORGIAN,
+    SCRIPT_HANGUL,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_CHEROKEE,
+    SCRIPT_CHEROKEE,
+    SCRIPT_CANADIAN_SYLLABICS,
+    SCRIPT_OGHAM,
+    SCRIPT_RUNIC,
+    SCRIPT_COMMON,
+    SCRIPT_RUNIC,
+    SCRIPT_TAGALOG,
+    SCRIPT_TAGALOG,
+    SCRIPT_HANUNOO,
+    SCRIPT_COMMON,
+    SCRIPT_BUHID,
+    SCRIPT_TAGBANWA,
+    SCRIPT_TAGBANWA,
+    SCRIPT_TAGBANWA,
+    SCRIPT_KHMER,
+    SCRIPT_KHMER,
+    SCRIPT_KHMER,
+    SCRIPT_MONGOLIAN,
+    SCRIPT_COMMON,
+    SCRIPT_MONGOLIAN,
+    SCRIPT_COMMON,
+    SCRIPT_MONGOLIAN,
+    SCRIPT_MONGOLIAN,
+    SCRIPT_MONGOLIAN,
+    SCRIPT_CANADIAN_SYLLABICS,
+    SCRIPT_LIMBU,
+    SCRIPT_LIMBU,
+    SCRIPT_LIMBU,
+    SCRIPT_LIMBU,
+    SCRIPT_LIMBU,
+    SCRIPT_TAI_LE,
+    SCRIPT_TAI_LE,
+    SCRIPT_NEW_TAI_LUE,
+    SCRIPT_NEW_TAI_LUE,
+    SCRIPT_NEW_TAI_LUE,
+    SCRIPT_NEW_TAI_LUE,
+    SCRIPT_KHMER,
+    SCRIPT_BUGINESE,
+    SCRIPT_BUGINESE,
+    SCRIPT_TAI_THAM,
+    SCRIPT_TAI_THAM,
+    SCRIPT_TAI_THAM,
+    SCRIPT_TAI_THAM,
+    SCRIPT_TAI_THAM,
+    SCRIPT_INHERITED,
+    SCRIPT_BALINESE,
+    SCRIPT_BALINESE,
+    SCRIPT_SUNDANESE,
+    SCRIPT_BATAK,
+    SCRIPT_BATAK,
+    SCRIPT_LEPCHA,
+    SCRIPT_LEPCHA,
+    SCRIPT_LEPCHA,
+    SCRIPT_OL_CHIKI,
+    SCRIPT_CYRILLIC,
+    SCRIPT_GEORGIAN,
+    SCRIPT_GEORGIAN,
+    SCRIPT_SUNDANESE,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_GREEK,
+    SCRIPT_CYRILLIC,
+    SCRIPT_LATIN,
+    SCRIPT_GREEK,
+    SCRIPT_LATIN,
+    SCRIPT_GREEK,
+    SCRIPT_LATIN,
+    SCRIPT_CYRILLIC,
+    SCRIPT_LATIN,
+    SCRIPT_GREEK,
+    SCRIPT_INHERITED,
+    SCRIPT_LATIN,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_GREEK,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_GREEK,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_BRAILLE,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_GLAGOLITIC,
+    SCRIPT_LATIN,
+    SCRIPT_COPTIC,
+    SCRIPT_COPTIC,
+    SCRIPT_GEORGIAN,
+    SCRIPT_GEORGIAN,
+    SCRIPT_GEORGIAN,
+    SCRIPT_TIFINAGH,
+    SCRIPT_TIFINAGH,
+    SCRIPT_TIFINAGH,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_CYRILLIC,
+    SCRIPT_COMMON,
+    SCRIPT_HAN,
+    SCRIPT_HAN,
+    SCRIPT_HAN,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_HAN,
+    SCRIPT_COMMON,
+    SCRIPT_HAN,
+    SCRIPT_COMMON,
+    SCRIPT_HAN,
+    SCRIPT_INHERITED,
+    SCRIPT_HANGUL,
+    SCRIPT_COMMON,
+    SCRIPT_HAN,
+    SCRIPT_COMMON,
+    SCRIPT_HIRAGANA,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_HIRAGANA,
+    SCRIPT_COMMON,
+    SCRIPT_KATAKANA,
+    SCRIPT_COMMON,
+    SCRIPT_KATAKANA,
+    SCRIPT_BOPOMOFO,
+    SCRIPT_HANGUL,
+    SCRIPT_COMMON,
+    SCRIPT_BOPOMOFO,
+    SCRIPT_COMMON,
+    SCRIPT_KATAKANA,
+    SCRIPT_HANGUL,
+    SCRIPT_COMMON,
+    SCRIPT_HANGUL,
+    SCRIPT_COMMON,
+    SCRIPT_KATAKANA,
+    SCRIPT_COMMON,
+    SCRIPT_KATAKANA,
+    SCRIPT_COMMON,
+    SCRIPT_HAN,
+    SCRIPT_COMMON,
+    SCRIPT_HAN,
+    SCRIPT_YI,
+    SCRIPT_YI,
+    SCRIPT_LISU,
+    SCRIPT_VAI,
+    SCRIPT_CYRILLIC,
+    SCRIPT_BAMUM,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_LATIN,
+    SCRIPT_LATIN,
+    SCRIPT_LATIN,
+    SCRIPT_LATIN,
+    SCRIPT_SYLOTI_NAGRI,
+    SCRIPT_COMMON,
+    SCRIPT_PHAGS_PA,
+    SCRIPT_SAURASHTRA,
+    SCRIPT_SAURASHTRA,
+    SCRIPT_DEVANAGARI,
+    SCRIPT_KAYAH_LI,
+    SCRIPT_COMMON,
+    SCRIPT_KAYAH_LI,
+    SCRIPT_REJANG,
+    SCRIPT_REJANG,
+    SCRIPT_HANGUL,
+    SCRIPT_JAVANESE,
+    SCRIPT_COMMON,
+    SCRIPT_JAVANESE,
+    SCRIPT_JAVANESE,
+    SCRIPT_MYANMAR,
+    SCRIPT_CHAM,
+    SCRIPT_CHAM,
+    SCRIPT_CHAM,
+    SCRIPT_CHAM,
+    SCRIPT_MYANMAR,
+    SCRIPT_TAI_VIET,
+    SCRIPT_TAI_VIET,
+    SCRIPT_MEETEI_MAYEK,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_GREEK,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_CHEROKEE,
+    SCRIPT_MEETEI_MAYEK,
+    SCRIPT_MEETEI_MAYEK,
+    SCRIPT_HANGUL,
+    SCRIPT_HANGUL,
+    SCRIPT_HANGUL,
+    SCRIPT_HAN,
+    SCRIPT_HAN,
+    SCRIPT_LATIN,
+    SCRIPT_ARMENIAN,
+    SCRIPT_HEBREW,
+    SCRIPT_HEBREW,
+    SCRIPT_HEBREW,
+    SCRIPT_HEBREW,
+    SCRIPT_HEBREW,
+    SCRIPT_HEBREW,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_COMMON,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_CYRILLIC,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_LATIN,
+    SCRIPT_COMMON,
+    SCRIPT_KATAKANA,
+    SCRIPT_COMMON,
+    SCRIPT_KATAKANA,
+    SCRIPT_COMMON,
+    SCRIPT_HANGUL,
+    SCRIPT_HANGUL,
+    SCRIPT_HANGUL,
+    SCRIPT_HANGUL,
+    SCRIPT_HANGUL,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_LINEAR_B,
+    SCRIPT_LINEAR_B,
+    SCRIPT_LINEAR_B,
+    SCRIPT_LINEAR_B,
+    SCRIPT_LINEAR_B,
+    SCRIPT_LINEAR_B,
+    SCRIPT_LINEAR_B,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_GREEK,
+    SCRIPT_COMMON,
+    SCRIPT_GREEK,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_LYCIAN,
+    SCRIPT_CARIAN,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_OLD_ITALIC,
+    SCRIPT_OLD_ITALIC,
+    SCRIPT_GOTHIC,
+    SCRIPT_OLD_PERMIC,
+    SCRIPT_UGARITIC,
+    SCRIPT_UGARITIC,
+    SCRIPT_OLD_PERSIAN,
+    SCRIPT_OLD_PERSIAN,
+    SCRIPT_DESERET,
+    SCRIPT_SHAVIAN,
+    SCRIPT_OSMANYA,
+    SCRIPT_OSMANYA,
+    SCRIPT_OSAGE,
+    SCRIPT_OSAGE,
+    SCRIPT_ELBASAN,
+    SCRIPT_CAUCASIAN_ALBANIAN,
+    SCRIPT_CAUCASIAN_ALBANIAN,
+    SCRIPT_LINEAR_A,
+    SCRIPT_LINEAR_A,
+    SCRIPT_LINEAR_A,
+    SCRIPT_LATIN,
+    SCRIPT_LATIN,
+    SCRIPT_LATIN,
+    SCRIPT_CYPRIOT,
+    SCRIPT_CYPRIOT,
+    SCRIPT_CYPRIOT,
+    SCRIPT_CYPRIOT,
+    SCRIPT_CYPRIOT,
+    SCRIPT_CYPRIOT,
+    SCRIPT_IMPERIAL_ARAMAIC,
+    SCRIPT_IMPERIAL_ARAMAIC,
+    SCRIPT_PALMYRENE,
+    SCRIPT_NABATAEAN,
+    SCRIPT_NABATAEAN,
+    SCRIPT_HATRAN,
+    SCRIPT_HATRAN,
+    SCRIPT_HATRAN,
+    SCRIPT_PHOENICIAN,
+    SCRIPT_PHOENICIAN,
+    SCRIPT_LYDIAN,
+    SCRIPT_LYDIAN,
+    SCRIPT_MEROITIC_HIEROGLYPHS,
+    SCRIPT_MEROITIC_CURSIVE,
+    SCRIPT_MEROITIC_CURSIVE,
+    SCRIPT_MEROITIC_CURSIVE,
+    SCRIPT_KHAROSHTHI,
+    SCRIPT_KHAROSHTHI,
+    SCRIPT_KHAROSHTHI,
+    SCRIPT_KHAROSHTHI,
+    SCRIPT_KHAROSHTHI,
+    SCRIPT_KHAROSHTHI,
+    SCRIPT_KHAROSHTHI,
+    SCRIPT_KHAROSHTHI,
+    SCRIPT_OLD_SOUTH_ARABIAN,
+    SCRIPT_OLD_NORTH_ARABIAN,
+    SCRIPT_MANICHAEAN,
+    SCRIPT_MANICHAEAN,
+    SCRIPT_AVESTAN,
+    SCRIPT_AVESTAN,
+    SCRIPT_INSCRIPTIONAL_PARTHIAN,
+    SCRIPT_INSCRIPTIONAL_PARTHIAN,
+    SCRIPT_INSCRIPTIONAL_PAHLAVI,
+    SCRIPT_INSCRIPTIONAL_PAHLAVI,
+    SCRIPT_PSALTER_PAHLAVI,
+    SCRIPT_PSALTER_PAHLAVI,
+    SCRIPT_PSALTER_PAHLAVI,
+    SCRIPT_OLD_TURKIC,
+    SCRIPT_OLD_HUNGARIAN,
+    SCRIPT_OLD_HUNGARIAN,
+    SCRIPT_OLD_HUNGARIAN,
+    SCRIPT_HANIFI_ROHINGYA,
+    SCRIPT_HANIFI_ROHINGYA,
+    SCRIPT_ARABIC,
+    SCRIPT_YEZIDI,
+    SCRIPT_YEZIDI,
+    SCRIPT_YEZIDI,
+    SCRIPT_ARABIC,
+    SCRIPT_OLD_SOGDIAN,
+    SCRIPT_SOGDIAN,
+    SCRIPT_CHORASMIAN,
+    SCRIPT_ELYMAIC,
+    SCRIPT_BRAHMI,
+    SCRIPT_BRAHMI,
+    SCRIPT_BRAHMI,
+    SCRIPT_KAITHI,
+    SCRIPT_KAITHI,
+    SCRIPT_SORA_SOMPENG,
+    SCRIPT_SORA_SOMPENG,
+    SCRIPT_CHAKMA,
+    SCRIPT_CHAKMA,
+    SCRIPT_MAHAJANI,
+    SCRIPT_SHARADA,
+    SCRIPT_SINHALA,
+    SCRIPT_KHOJKI,
+    SCRIPT_KHOJKI,
+    SCRIPT_MULTANI,
+    SCRIPT_MULTANI,
+    SCRIPT_MULTANI,
+    SCRIPT_MULTANI,
+    SCRIPT_MULTANI,
+    SCRIPT_KHUDAWADI,
+    SCRIPT_KHUDAWADI,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_INHERITED,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_GRANTHA,
+    SCRIPT_NEWA,
+    SCRIPT_NEWA,
+    SCRIPT_TIRHUTA,
+    SCRIPT_TIRHUTA,
+    SCRIPT_SIDDHAM,
+    SCRIPT_SIDDHAM,
+    SCRIPT_MODI,
+    SCRIPT_MODI,
+    SCRIPT_MONGOLIAN,
+    SCRIPT_TAKRI,
+    SCRIPT_TAKRI,
+    SCRIPT_AHOM,
+    SCRIPT_AHOM,
+    SCRIPT_AHOM,
+    SCRIPT_DOGRA,
+    SCRIPT_WARANG_CITI,
+    SCRIPT_WARANG_CITI,
+    SCRIPT_DIVES_AKURU,
+    SCRIPT_DIVES_AKURU,
+    SCRIPT_DIVES_AKURU,
+    SCRIPT_DIVES_AKURU,
+    SCRIPT_DIVES_AKURU,
+    SCRIPT_DIVES_AKURU,
+    SCRIPT_DIVES_AKURU,
+    SCRIPT_DIVES_AKURU,
+    SCRIPT_NANDINAGARI,
+    SCRIPT_NANDINAGARI,
+    SCRIPT_NANDINAGARI,
+    SCRIPT_ZANABAZAR_SQUARE,
+    SCRIPT_SOYOMBO,
+    SCRIPT_CANADIAN_SYLLABICS,
+    SCRIPT_PAU_CIN_HAU,
+    SCRIPT_DEVANAGARI,
+    SCRIPT_BHAIKSUKI,
+    SCRIPT_BHAIKSUKI,
+    SCRIPT_BHAIKSUKI,
+    SCRIPT_BHAIKSUKI,
+    SCRIPT_MARCHEN,
+    SCRIPT_MARCHEN,
+    SCRIPT_MARCHEN,
+    SCRIPT_MASARAM_GONDI,
+    SCRIPT_MASARAM_GONDI,
+    SCRIPT_MASARAM_GONDI,
+    SCRIPT_MASARAM_GONDI,
+    SCRIPT_MASARAM_GONDI,
+    SCRIPT_MASARAM_GONDI,
+    SCRIPT_MASARAM_GONDI,
+    SCRIPT_GUNJALA_GONDI,
+    SCRIPT_GUNJALA_GONDI,
+    SCRIPT_GUNJALA_GONDI,
+    SCRIPT_GUNJALA_GONDI,
+    SCRIPT_GUNJALA_GONDI,
+    SCRIPT_GUNJALA_GONDI,
+    SCRIPT_MAKASAR,
+    SCRIPT_LISU,
+    SCRIPT_TAMIL,
+    SCRIPT_TAMIL,
+    SCRIPT_CUNEIFORM,
+    SCRIPT_CUNEIFORM,
+    SCRIPT_CUNEIFORM,
+    SCRIPT_CUNEIFORM,
+    SCRIPT_EGYPTIAN_HIEROGLYPHS,
+    SCRIPT_ANATOLIAN_HIEROGLYPHS,
+    SCRIPT_BAMUM,
+    SCRIPT_MRO,
+    SCRIPT_MRO,
+    SCRIPT_MRO,
+    SCRIPT_BASSA_VAH,
+    SCRIPT_BASSA_VAH,
+    SCRIPT_PAHAWH_HMONG,
+    SCRIPT_PAHAWH_HMONG,
+    SCRIPT_PAHAWH_HMONG,
+    SCRIPT_PAHAWH_HMONG,
+    SCRIPT_PAHAWH_HMONG,
+    SCRIPT_MEDEFAIDRIN,
+    SCRIPT_MIAO,
+    SCRIPT_MIAO,
+    SCRIPT_MIAO,
+    SCRIPT_TANGUT,
+    SCRIPT_NUSHU,
+    SCRIPT_HAN,
+    SCRIPT_KHITAN_SMALL_SCRIPT,
+    SCRIPT_HAN,
+    SCRIPT_TANGUT,
+    SCRIPT_TANGUT,
+    SCRIPT_KHITAN_SMALL_SCRIPT,
+    SCRIPT_TANGUT,
+    SCRIPT_KATAKANA,
+    SCRIPT_KATAKANA,
+    SCRIPT_KATAKANA,
+    SCRIPT_KATAKANA,
+    SCRIPT_HIRAGANA,
+    SCRIPT_KATAKANA,
+    SCRIPT_HIRAGANA,
+    SCRIPT_HIRAGANA,
+    SCRIPT_KATAKANA,
+    SCRIPT_KATAKANA,
+    SCRIPT_NUSHU,
+    SCRIPT_DUPLOYAN,
+    SCRIPT_DUPLOYAN,
+    SCRIPT_DUPLOYAN,
+    SCRIPT_DUPLOYAN,
+    SCRIPT_DUPLOYAN,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
+    SCRIPT_COMMON,
+    SCRIPT_GREEK,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_SIGNWRITING,
+    SCRIPT_SIGNWRITING,
+    SCRIPT_SIGNWRITING,
+    SCRIPT_LATIN,
+    SCRIPT_LATIN,
+    SCRIPT_GLAGOLITIC,
+    SCRIPT_GLAGOLITIC,
+    SCRIPT_GLAGOLITIC,
+    SCRIPT_GLAGOLITIC,
+    SCRIPT_GLAGOLITIC,
+    SCRIPT_CYRILLIC,
+    SCRIPT_CYRILLIC,
+    SCRIPT_NYIAKENG_PUACHUE_HMONG,
+    SCRIPT_NYIAKENG_PUACHUE_HMONG,
+    SCRIPT_NYIAKENG_PUACHUE_HMONG,
+    SCRIPT_NYIAKENG_PUACHUE_HMONG,
+    SCRIPT_WANCHO,
+    SCRIPT_WANCHO,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_ETHIOPIC,
+    SCRIPT_MENDE_KIKAKUI,
+    SCRIPT_MENDE_KIKAKUI,
+    SCRIPT_ADLAM,
+    SCRIPT_ADLAM,
+    SCRIPT_ADLAM,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_ARABIC,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_HIRAGANA,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_HAN,
+    SCRIPT_HAN,
+    SCRIPT_HAN,
+    SCRIPT_HAN,
+    SCRIPT_HAN,
+    SCRIPT_HAN,
+    SCRIPT_HAN,
+    SCRIPT_HAN,
+    SCRIPT_COMMON,
+    SCRIPT_COMMON,
+    SCRIPT_INHERITED,
 ];
-pub const MIRROR_KEY: [u32; 420] = [
+// Warning: script Old_Uyghur not known by HarfBuzz
+// Warning: script Cypro_Minoan not known by HarfBuzz
+pub static SCRIPT_EXT_KEY: [(u32, u32); 154] = [
+    (0x342, 0x343),
+    (0x345, 0x346),
+    (0x363, 0x370),
+    (0x483, 0x484),
+    (0x484, 0x485),
+    (0x485, 0x487),
+    (0x487, 0x488),
+    (0x60c, 0x60d),
+    (0x61b, 0x61c),
+    (0x61c, 0x61d),
+    (0x61f, 0x620),
+    (0x640, 0x641),
+    (0x64b, 0x656),
+    (0x660, 0x66a),
+    (0x670, 0x671),
+    (0x6d4, 0x6d5),
+    (0x951, 0x952),
+    (0x952, 0x953),
+    (0x964, 0x965),
+    (0x965, 0x966),
+    (0x966, 0x970),
+    (0x9e6, 0x9f0),
+    (0xa66, 0xa70),
+    (0xae6, 0xaf0),
+    (0xbe6, 0xbf0),
+    (0xbf0, 0xbf3),
+    (0xbf3, 0xbf4),
+    (0xce6, 0xcf0),
+    (0x1040, 0x104a),
+    (0x10fb, 0x10fc),
+    (0x1735, 0x1737),
+    (0x1802, 0x1804),
+    (0x1805, 0x1806),
+    (0x1cd0, 0x1cd1),
+    (0x1cd1, 0x1cd2),
+    (0x1cd2, 0x1cd3),
+    (0x1cd3, 0x1cd4),
+    (0x1cd4, 0x1cd5),
+    (0x1cd5, 0x1cd7),
+    (0x1cd7, 0x1cd8),
+    (0x1cd8, 0x1cd9),
+    (0x1cd9, 0x1cda),
+    (0x1cda, 0x1cdb),
+    (0x1cdb, 0x1cdc),
+    (0x1cdc, 0x1cde),
+    (0x1cde, 0x1ce0),
+    (0x1ce0, 0x1ce1),
+    (0x1ce1, 0x1ce2),
+    (0x1ce2, 0x1ce9),
+    (0x1ce9, 0x1cea),
+    (0x1cea, 0x1ceb),
+    (0x1ceb, 0x1ced),
+    (0x1ced, 0x1cee),
+    (0x1cee, 0x1cf2),
+    (0x1cf2, 0x1cf3),
+    (0x1cf3, 0x1cf4),
+    (0x1cf4, 0x1cf5),
+    (0x1cf5, 0x1cf7),
+    (0x1cf7, 0x1cf8),
+    (0x1cf8, 0x1cfa),
+    (0x1cfa, 0x1cfb),
+    (0x1dc0, 0x1dc2),
+    (0x1df8, 0x1df9),
+    (0x1dfa, 0x1dfb),
+    (0x202f, 0x2030),
+    (0x20f0, 0x20f1),
+    (0x2e43, 0x2e44),
+    (0x3001, 0x3003),
+    (0x3003, 0x3004),
+    (0x3006, 0x3007),
+    (0x3008, 0x3009),
+    (0x3009, 0x300a),
+    (0x300a, 0x300b),
+    (0x300b, 0x300c),
+    (0x300c, 0x300d),
+    (0x300d, 0x300e),
+    (0x300e, 0x300f),
+    (0x300f, 0x3010),
+    (0x3010, 0x3011),
+    (0x3011, 0x3012),
+    (0x3013, 0x3014),
+    (0x3014, 0x3015),
+    (0x3015, 0x3016),
+    (0x3016, 0x3017),
+    (0x3017, 0x3018),
+    (0x3018, 0x3019),
+    (0x3019, 0x301a),
+    (0x301a, 0x301b),
+    (0x301b, 0x301c),
+    (0x301c, 0x301d),
+    (0x301d, 0x301e),
+    (0x301e, 0x3020),
+    (0x302a, 0x302e),
+    (0x3030, 0x3031),
+    (0x3031, 0x3036),
+    (0x3037, 0x3038),
+    (0x303c, 0x303d),
+    (0x303d, 0x303e),
+    (0x303e, 0x3040),
+    (0x3099, 0x309b),
+    (0x309b, 0x309d),
+    (0x30a0, 0x30a1),
+    (0x30fb, 0x30fc),
+    (0x30fc, 0x30fd),
+    (0x3190, 0x3192),
+    (0x3192, 0x3196),
+    (0x3196, 0x31a0),
+    (0x31c0, 0x31e4),
+    (0x3220, 0x322a),
+    (0x322a, 0x3248),
+    (0x3280, 0x328a),
+    (0x328a, 0x32b1),
+    (0x32c0, 0x32cc),
+    (0x32ff, 0x3300),
+    (0x3358, 0x3371),
+    (0x337b, 0x3380),
+    (0x33e0, 0x33ff),
+    (0xa66f, 0xa670),
+    (0xa700, 0xa708),
+    (0xa830, 0xa833),
+    (0xa833, 0xa836),
+    (0xa836, 0xa838),
+    (0xa838, 0xa839),
+    (0xa839, 0xa83a),
+    (0xa8f1, 0xa8f2),
+    (0xa8f3, 0xa8f4),
+    (0xa92e, 0xa92f),
+    (0xa9cf, 0xa9d0),
+    (0xfd3e, 0xfd3f),
+    (0xfd3f, 0xfd40),
+    (0xfdf2, 0xfdf3),
+    (0xfdfd, 0xfdfe),
+    (0xfe45, 0xfe47),
+    (0xff61, 0xff62),
+    (0xff62, 0xff63),
+    (0xff63, 0xff64),
+    (0xff64, 0xff66),
+    (0xff70, 0xff71),
+    (0xff9e, 0xffa0),
+    (0x10100, 0x10102),
+    (0x10102, 0x10103),
+    (0x10107, 0x10134),
+    (0x10137, 0x10140),
+    (0x102e0, 0x102e1),
+    (0x102e1, 0x102fc),
+    (0x10af2, 0x10af3),
+    (0x11301, 0x11302),
+    (0x11303, 0x11304),
+    (0x1133b, 0x1133d),
+    (0x11fd0, 0x11fd2),
+    (0x11fd3, 0x11fd4),
+    (0x1bca0, 0x1bca4),
+    (0x1d360, 0x1d372),
+    (0x1f250, 0x1f252),
+];
+pub static SCRIPT_EXT_VAL: [&[Script]; 154] = [
+    &[SCRIPT_GREEK],
+    &[SCRIPT_GREEK],
+    &[SCRIPT_LATIN],
+    &[SCRIPT_CYRILLIC, SCRIPT_OLD_PERMIC],
+    &[SCRIPT_CYRILLIC, SCRIPT_GLAGOLITIC],
+    &[SCRIPT_CYRILLIC, SCRIPT_LATIN],
+    &[SCRIPT_CYRILLIC, SCRIPT_GLAGOLITIC],
+    &[
+        SCRIPT_ARABIC,
+        SCRIPT_NKO,
+        SCRIPT_HANIFI_ROHINGYA,
+        SCRIPT_SYRIAC,
+        SCRIPT_THAANA,
+        SCRIPT_YEZIDI,
+    ],
+    &[
+        SCRIPT_ARABIC,
+        SCRIPT_NKO,
+        SCRIPT_HANIFI_ROHINGYA,
+        SCRIPT_SYRIAC,
+        SCRIPT_THAANA,
+        SCRIPT_YEZIDI,
+    ],
+    &[SCRIPT_ARABIC, SCRIPT_SYRIAC, SCRIPT_THAANA],
+    &[
+        SCRIPT_ADLAM,
+        SCRIPT_ARABIC,
+        SCRIPT_NKO,
+        SCRIPT_HANIFI_ROHINGYA,
+        SCRIPT_SYRIAC,
+        SCRIPT_THAANA,
+        SCRIPT_YEZIDI,
+    ],
+    &[
+        SCRIPT_ADLAM,
+        SCRIPT_ARABIC,
+        SCRIPT_MANDAIC,
+        SCRIPT_MANICHAEAN,
+        SCRIPT_PSALTER_PAHLAVI,
+        SCRIPT_HANIFI_ROHINGYA,
+        SCRIPT_SOGDIAN,
+        SCRIPT_SYRIAC,
+    ],
+    &[SCRIPT_ARABIC, SCRIPT_SYRIAC],
+    &[SCRIPT_ARABIC, SCRIPT_THAANA, SCRIPT_YEZIDI],
+    &[SCRIPT_ARABIC, SCRIPT_SYRIAC],
+    &[SCRIPT_ARABIC, SCRIPT_HANIFI_ROHINGYA],
+    &[
+        SCRIPT_BENGALI,
+        SCRIPT_DEVANAGARI,
+        SCRIPT_GRANTHA,
+        SCRIPT_GUJARATI,
+        SCRIPT_GURMUKHI,
+        SCRIPT_KANNADA,
+        SCRIPT_LATIN,
+        SCRIPT_MALAYALAM,
+        SCRIPT_ORIYA,
+        SCRIPT_SHARADA,
+        SCRIPT_TAMIL,
+        SCRIPT_TELUGU,
+        SCRIPT_TIRHUTA,
+    ],
+    &[
+        SCRIPT_BENGALI,
+        SCRIPT_DEVANAGARI,
+        SCRIPT_GRANTHA,
+        SCRIPT_GUJARATI,
+        SCRIPT_GURMUKHI,
+        SCRIPT_KANNADA,
+        SCRIPT_LATIN,
+        SCRIPT_MALAYALAM,
+        SCRIPT_ORIYA,
+        SCRIPT_TAMIL,
+        SCRIPT_TELUGU,
+        SCRIPT_TIRHUTA,
+    ],
+    &[
+        SCRIPT_BENGALI,
+        SCRIPT_DEVANAGARI,
+        SCRIPT_DOGRA,
+        SCRIPT_GUNJALA_GONDI,
+        SCRIPT_MASARAM_GONDI,
+        SCRIPT_GRANTHA,
+        SCRIPT_GUJARATI,
+        SCRIPT_GURMUKHI,
+        SCRIPT_KANNADA,
+        SCRIPT_MAHAJANI,
+        SCRIPT_MALAYALAM,
+        SCRIPT_NANDINAGARI,
+        SCRIPT_ORIYA,
+        SCRIPT_KHUDAWADI,
+        SCRIPT_SINHALA,
+        SCRIPT_SYLOTI_NAGRI,
+        SCRIPT_TAKRI,
+        SCRIPT_TAMIL,
+        SCRIPT_TELUGU,
+        SCRIPT_TIRHUTA,
+    ],
+    &[
+        SCRIPT_BENGALI,
+        SCRIPT_DEVANAGARI,
+        SCRIPT_DOGRA,
+        SCRIPT_GUNJALA_GONDI,
+        SCRIPT_MASARAM_GONDI,
+        SCRIPT_GRANTHA,
+        SCRIPT_GUJARATI,
+        SCRIPT_GURMUKHI,
+        SCRIPT_KANNADA,
+        SCRIPT_LIMBU,
+        SCRIPT_MAHAJANI,
+        SCRIPT_MALAYALAM,
+        SCRIPT_NANDINAGARI,
+        SCRIPT_ORIYA,
+        SCRIPT_KHUDAWADI,
+        SCRIPT_SINHALA,
+        SCRIPT_SYLOTI_NAGRI,
+        SCRIPT_TAKRI,
+        SCRIPT_TAMIL,
+        SCRIPT_TELUGU,
+        SCRIPT_TIRHUTA,
+    ],
+    &[
+        SCRIPT_DEVANAGARI,
+        SCRIPT_DOGRA,
+        SCRIPT_KAITHI,
+        SCRIPT_MAHAJANI,
+    ],
+    &[SCRIPT_BENGALI, SCRIPT_CHAKMA, SCRIPT_SYLOTI_NAGRI],
+    &[SCRIPT_GURMUKHI, SCRIPT_MULTANI],
+    &[SCRIPT_GUJARATI, SCRIPT_KHOJKI],
+    &[SCRIPT_GRANTHA, SCRIPT_TAMIL],
+    &[SCRIPT_GRANTHA, SCRIPT_TAMIL],
+    &[SCRIPT_GRANTHA, SCRIPT_TAMIL],
+    &[SCRIPT_KANNADA, SCRIPT_NANDINAGARI],
+    &[SCRIPT_CHAKMA, SCRIPT_MYANMAR, SCRIPT_TAI_LE],
+    &[SCRIPT_GEORGIAN, SCRIPT_LATIN],
+    &[
+        SCRIPT_BUHID,
+        SCRIPT_HANUNOO,
+        SCRIPT_TAGBANWA,
+        SCRIPT_TAGALOG,
+    ],
+    &[SCRIPT_MONGOLIAN, SCRIPT_PHAGS_PA],
+    &[SCRIPT_MONGOLIAN, SCRIPT_PHAGS_PA],
+    &[
+        SCRIPT_BENGALI,
+        SCRIPT_DEVANAGARI,
+        SCRIPT_GRANTHA,
+        SCRIPT_KANNADA,
+    ],
+    &[SCRIPT_DEVANAGARI],
+    &[
+        SCRIPT_BENGALI,
+        SCRIPT_DEVANAGARI,
+        SCRIPT_GRANTHA,
+        SCRIPT_KANNADA,
+    ],
+    &[SCRIPT_DEVANAGARI, SCRIPT_GRANTHA],
+    &[SCRIPT_DEVANAGARI],
+    &[SCRIPT_BENGALI, SCRIPT_DEVANAGARI],
+    &[SCRIPT_DEVANAGARI, SCRIPT_SHARADA],
+    &[SCRIPT_BENGALI, SCRIPT_DEVANAGARI],
+    &[SCRIPT_DEVANAGARI, SCRIPT_SHARADA],
+    &[
+        SCRIPT_DEVANAGARI,
+        SCRIPT_KANNADA,
+        SCRIPT_MALAYALAM,
+        SCRIPT_ORIYA,
+        SCRIPT_TAMIL,
+        SCRIPT_TELUGU,
+    ],
+    &[SCRIPT_DEVANAGARI],
+    &[SCRIPT_DEVANAGARI, SCRIPT_SHARADA],
+    &[SCRIPT_DEVANAGARI],
+    &[SCRIPT_DEVANAGARI, SCRIPT_SHARADA],
+    &[SCRIPT_BENGALI, SCRIPT_DEVANAGARI],
+    &[SCRIPT_DEVANAGARI],
+    &[SCRIPT_DEVANAGARI, SCRIPT_NANDINAGARI],
+    &[SCRIPT_BENGALI, SCRIPT_DEVANAGARI],
+    &[SCRIPT_DEVANAGARI],
+    &[SCRIPT_BENGALI, SCRIPT_DEVANAGARI],
+    &[SCRIPT_DEVANAGARI],
+    &[
+        SCRIPT_BENGALI,
+        SCRIPT_DEVANAGARI,
+        SCRIPT_GRANTHA,
+        SCRIPT_KANNADA,
+        SCRIPT_NANDINAGARI,
+        SCRIPT_ORIYA,
+        SCRIPT_TELUGU,
+        SCRIPT_TIRHUTA,
+    ],
+    &[SCRIPT_DEVANAGARI, SCRIPT_GRANTHA],
+    &[SCRIPT_DEVANAGARI, SCRIPT_GRANTHA, SCRIPT_KANNADA],
+    &[SCRIPT_BENGALI, SCRIPT_DEVANAGARI],
+    &[SCRIPT_BENGALI],
+    &[SCRIPT_DEVANAGARI, SCRIPT_GRANTHA],
+    &[SCRIPT_NANDINAGARI],
+    &[SCRIPT_GREEK],
+    &[SCRIPT_CYRILLIC, SCRIPT_SYRIAC],
+    &[SCRIPT_SYRIAC],
+    &[SCRIPT_LATIN, SCRIPT_MONGOLIAN],
+    &[SCRIPT_DEVANAGARI, SCRIPT_GRANTHA, SCRIPT_LATIN],
+    &[SCRIPT_CYRILLIC, SCRIPT_GLAGOLITIC],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+    ],
+    &[SCRIPT_HAN],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+    ],
+    &[SCRIPT_BOPOMOFO, SCRIPT_HAN],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+    ],
+    &[SCRIPT_HIRAGANA, SCRIPT_KATAKANA],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+    ],
+    &[SCRIPT_HAN, SCRIPT_HIRAGANA, SCRIPT_KATAKANA],
+    &[SCRIPT_HAN, SCRIPT_HIRAGANA, SCRIPT_KATAKANA],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HIRAGANA, SCRIPT_KATAKANA],
+    &[SCRIPT_HIRAGANA, SCRIPT_KATAKANA],
+    &[SCRIPT_HIRAGANA, SCRIPT_KATAKANA],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[SCRIPT_HIRAGANA, SCRIPT_KATAKANA],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_CYRILLIC, SCRIPT_GLAGOLITIC],
+    &[SCRIPT_HAN, SCRIPT_LATIN],
+    &[
+        SCRIPT_DEVANAGARI,
+        SCRIPT_DOGRA,
+        SCRIPT_GUJARATI,
+        SCRIPT_GURMUKHI,
+        SCRIPT_KHOJKI,
+        SCRIPT_KANNADA,
+        SCRIPT_KAITHI,
+        SCRIPT_MAHAJANI,
+        SCRIPT_MALAYALAM,
+        SCRIPT_MODI,
+        SCRIPT_NANDINAGARI,
+        SCRIPT_KHUDAWADI,
+        SCRIPT_TAKRI,
+        SCRIPT_TIRHUTA,
+    ],
+    &[
+        SCRIPT_DEVANAGARI,
+        SCRIPT_DOGRA,
+        SCRIPT_GUJARATI,
+        SCRIPT_GURMUKHI,
+        SCRIPT_KHOJKI,
+        SCRIPT_KANNADA,
+        SCRIPT_KAITHI,
+        SCRIPT_MAHAJANI,
+        SCRIPT_MODI,
+        SCRIPT_NANDINAGARI,
+        SCRIPT_KHUDAWADI,
+        SCRIPT_TAKRI,
+        SCRIPT_TIRHUTA,
+    ],
+    &[
+        SCRIPT_DEVANAGARI,
+        SCRIPT_DOGRA,
+        SCRIPT_GUJARATI,
+        SCRIPT_GURMUKHI,
+        SCRIPT_KHOJKI,
+        SCRIPT_KAITHI,
+        SCRIPT_MAHAJANI,
+        SCRIPT_MODI,
+        SCRIPT_KHUDAWADI,
+        SCRIPT_TAKRI,
+        SCRIPT_TIRHUTA,
+    ],
+    &[
+        SCRIPT_DEVANAGARI,
+        SCRIPT_DOGRA,
+        SCRIPT_GUJARATI,
+        SCRIPT_GURMUKHI,
+        SCRIPT_KHOJKI,
+        SCRIPT_KAITHI,
+        SCRIPT_MAHAJANI,
+        SCRIPT_MODI,
+        SCRIPT_KHUDAWADI,
+        SCRIPT_TAKRI,
+        SCRIPT_TIRHUTA,
+    ],
+    &[
+        SCRIPT_DEVANAGARI,
+        SCRIPT_DOGRA,
+        SCRIPT_GUJARATI,
+        SCRIPT_GURMUKHI,
+        SCRIPT_KHOJKI,
+        SCRIPT_KAITHI,
+        SCRIPT_MAHAJANI,
+        SCRIPT_MODI,
+        SCRIPT_KHUDAWADI,
+        SCRIPT_TAKRI,
+        SCRIPT_TIRHUTA,
+    ],
+    &[SCRIPT_BENGALI, SCRIPT_DEVANAGARI],
+    &[SCRIPT_DEVANAGARI, SCRIPT_TAMIL],
+    &[SCRIPT_KAYAH_LI, SCRIPT_LATIN, SCRIPT_MYANMAR],
+    &[SCRIPT_BUGINESE, SCRIPT_JAVANESE],
+    &[SCRIPT_ARABIC, SCRIPT_NKO],
+    &[SCRIPT_ARABIC, SCRIPT_NKO],
+    &[SCRIPT_ARABIC, SCRIPT_THAANA],
+    &[SCRIPT_ARABIC, SCRIPT_THAANA],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[
+        SCRIPT_BOPOMOFO,
+        SCRIPT_HANGUL,
+        SCRIPT_HAN,
+        SCRIPT_HIRAGANA,
+        SCRIPT_KATAKANA,
+        SCRIPT_YI,
+    ],
+    &[SCRIPT_HIRAGANA, SCRIPT_KATAKANA],
+    &[SCRIPT_HIRAGANA, SCRIPT_KATAKANA],
+    &[SCRIPT_CYPRIOT, SCRIPT_LINEAR_B],
+    &[SCRIPT_CYPRIOT, SCRIPT_LINEAR_B],
+    &[SCRIPT_CYPRIOT, SCRIPT_LINEAR_A, SCRIPT_LINEAR_B],
+    &[SCRIPT_CYPRIOT, SCRIPT_LINEAR_B],
+    &[SCRIPT_ARABIC, SCRIPT_COPTIC],
+    &[SCRIPT_ARABIC, SCRIPT_COPTIC],
+    &[SCRIPT_MANICHAEAN],
+    &[SCRIPT_GRANTHA, SCRIPT_TAMIL],
+    &[SCRIPT_GRANTHA, SCRIPT_TAMIL],
+    &[SCRIPT_GRANTHA, SCRIPT_TAMIL],
+    &[SCRIPT_GRANTHA, SCRIPT_TAMIL],
+    &[SCRIPT_GRANTHA, SCRIPT_TAMIL],
+    &[SCRIPT_DUPLOYAN],
+    &[SCRIPT_HAN],
+    &[SCRIPT_HAN],
+];
+pub static MIRROR_KEY: [u32; 428] = [
     0x28,   // -> 0x29
     0x29,   // -> 0x28
     0x3c,   // -> 0x3e
@@ -4402,6 +5251,14 @@ pub const MIRROR_KEY: [u32; 420] = [
     0x2e27, // -> 0x2e26
     0x2e28, // -> 0x2e29
     0x2e29, // -> 0x2e28
+    0x2e55, // -> 0x2e56
+    0x2e56, // -> 0x2e55
+    0x2e57, // -> 0x2e58
+    0x2e58, // -> 0x2e57
+    0x2e59, // -> 0x2e5a
+    0x2e5a, // -> 0x2e59
+    0x2e5b, // -> 0x2e5c
+    0x2e5c, // -> 0x2e5b
     0x3008, // -> 0x3009
     0x3009, // -> 0x3008
     0x300a, // -> 0x300b
@@ -4441,7 +5298,7 @@ pub const MIRROR_KEY: [u32; 420] = [
     0xff62, // -> 0xff63
     0xff63, // -> 0xff62
 ];
-pub const MIRROR_VAL: [u32; 420] = [
+pub static MIRROR_VAL: [u32; 428] = [
     0x29,   // <- 0x28
     0x28,   // <- 0x29
     0x3e,   // <- 0x3c
@@ -4824,6 +5681,14 @@ pub const MIRROR_VAL: [u32; 420] = [
     0x2e26, // <- 0x2e27
     0x2e29, // <- 0x2e28
     0x2e28, // <- 0x2e29
+    0x2e56, // <- 0x2e55
+    0x2e55, // <- 0x2e56
+    0x2e58, // <- 0x2e57
+    0x2e57, // <- 0x2e58
+    0x2e5a, // <- 0x2e59
+    0x2e59, // <- 0x2e5a
+    0x2e5c, // <- 0x2e5b
+    0x2e5b, // <- 0x2e5c
     0x3009, // <- 0x3008
     0x3008, // <- 0x3009
     0x300b, // <- 0x300a
@@ -4863,3 +5728,619 @@ pub const MIRROR_VAL: [u32; 420] = [
     0xff63, // <- 0xff62
     0xff62, // <- 0xff63
 ];
+pub static PAIRED_BRACKET_KEY: [u32; 128] = [
+    0x28,   // o
+    0x29,   // c
+    0x5b,   // o
+    0x5d,   // c
+    0x7b,   // o
+    0x7d,   // c
+    0xf3a,  // o
+    0xf3b,  // c
+    0xf3c,  // o
+    0xf3d,  // c
+    0x169b, // o
+    0x169c, // c
+    0x2045, // o
+    0x2046, // c
+    0x207d, // o
+    0x207e, // c
+    0x208d, // o
+    0x208e, // c
+    0x2308, // o
+    0x2309, // c
+    0x230a, // o
+    0x230b, // c
+    0x2329, // o
+    0x232a, // c
+    0x2768, // o
+    0x2769, // c
+    0x276a, // o
+    0x276b, // c
+    0x276c, // o
+    0x276d, // c
+    0x276e, // o
+    0x276f, // c
+    0x2770, // o
+    0x2771, // c
+    0x2772, // o
+    0x2773, // c
+    0x2774, // o
+    0x2775, // c
+    0x27c5, // o
+    0x27c6, // c
+    0x27e6, // o
+    0x27e7, // c
+    0x27e8, // o
+    0x27e9, // c
+    0x27ea, // o
+    0x27eb, // c
+    0x27ec, // o
+    0x27ed, // c
+    0x27ee, // o
+    0x27ef, // c
+    0x2983, // o
+    0x2984, // c
+    0x2985, // o
+    0x2986, // c
+    0x2987, // o
+    0x2988, // c
+    0x2989, // o
+    0x298a, // c
+    0x298b, // o
+    0x298c, // c
+    0x298d, // o
+    0x298e, // c
+    0x298f, // o
+    0x2990, // c
+    0x2991, // o
+    0x2992, // c
+    0x2993, // o
+    0x2994, // c
+    0x2995, // o
+    0x2996, // c
+    0x2997, // o
+    0x2998, // c
+    0x29d8, // o
+    0x29d9, // c
+    0x29da, // o
+    0x29db, // c
+    0x29fc, // o
+    0x29fd, // c
+    0x2e22, // o
+    0x2e23, // c
+    0x2e24, // o
+    0x2e25, // c
+    0x2e26, // o
+    0x2e27, // c
+    0x2e28, // o
+    0x2e29, // c
+    0x2e55, // o
+    0x2e56, // c
+    0x2e57, // o
+    0x2e58, // c
+    0x2e59, // o
+    0x2e5a, // c
+    0x2e5b, // o
+    0x2e5c, // c
+    0x3008, // o
+    0x3009, // c
+    0x300a, // o
+    0x300b, // c
+    0x300c, // o
+    0x300d, // c
+    0x300e, // o
+    0x300f, // c
+    0x3010, // o
+    0x3011, // c
+    0x3014, // o
+    0x3015, // c
+    0x3016, // o
+    0x3017, // c
+    0x3018, // o
+    0x3019, // c
+    0x301a, // o
+    0x301b, // c
+    0xfe59, // o
+    0xfe5a, // c
+    0xfe5b, // o
+    0xfe5c, // c
+    0xfe5d, // o
+    0xfe5e, // c
+    0xff08, // o
+    0xff09, // c
+    0xff3b, // o
+    0xff3d, // c
+    0xff5b, // o
+    0xff5d, // c
+    0xff5f, // o
+    0xff60, // c
+    0xff62, // o
+    0xff63, // c
+];
+pub static PAIRED_BRACKET_VAL: [u32; 128] = [
+    0x28,   // <- 0x28
+    0x28,   // <- 0x29
+    0x5b,   // <- 0x5b
+    0x5b,   // <- 0x5d
+    0x7b,   // <- 0x7b
+    0x7b,   // <- 0x7d
+    0xf3a,  // <- 0xf3a
+    0xf3a,  // <- 0xf3b
+    0xf3c,  // <- 0xf3c
+    0xf3c,  // <- 0xf3d
+    0x169b, // <- 0x169b
+    0x169b, // <- 0x169c
+    0x2045, // <- 0x2045
+    0x2045, // <- 0x2046
+    0x207d, // <- 0x207d
+    0x207d, // <- 0x207e
+    0x208d, // <- 0x208d
+    0x208d, // <- 0x208e
+    0x2308, // <- 0x2308
+    0x2308, // <- 0x2309
+    0x230a, // <- 0x230a
+    0x230a, // <- 0x230b
+    0x2329, // <- 0x2329
+    0x2329, // <- 0x232a
+    0x2768, // <- 0x2768
+    0x2768, // <- 0x2769
+    0x276a, // <- 0x276a
+    0x276a, // <- 0x276b
+    0x276c, // <- 0x276c
+    0x276c, // <- 0x276d
+    0x276e, // <- 0x276e
+    0x276e, // <- 0x276f
+    0x2770, // <- 0x2770
+    0x2770, // <- 0x2771
+    0x2772, // <- 0x2772
+    0x2772, // <- 0x2773
+    0x2774, // <- 0x2774
+    0x2774, // <- 0x2775
+    0x27c5, // <- 0x27c5
+    0x27c5, // <- 0x27c6
+    0x27e6, // <- 0x27e6
+    0x27e6, // <- 0x27e7
+    0x27e8, // <- 0x27e8
+    0x27e8, // <- 0x27e9
+    0x27ea, // <- 0x27ea
+    0x27ea, // <- 0x27eb
+    0x27ec, // <- 0x27ec
+    0x27ec, // <- 0x27ed
+    0x27ee, // <- 0x27ee
+    0x27ee, // <- 0x27ef
+    0x2983, // <- 0x2983
+    0x2983, // <- 0x2984
+    0x2985, // <- 0x2985
+    0x2985, // <- 0x2986
+    0x2987, // <- 0x2987
+    0x2987, // <- 0x2988
+    0x2989, // <- 0x2989
+    0x2989, // <- 0x298a
+    0x298b, // <- 0x298b
+    0x298b, // <- 0x298c
+    0x298d, // <- 0x298d
+    0x298f, // <- 0x298e
+    0x298f, // <- 0x298f
+    0x298d, // <- 0x2990
+    0x2991, // <- 0x2991
+    0x2991, // <- 0x2992
+    0x2993, // <- 0x2993
+    0x2993, // <- 0x2994
+    0x2995, // <- 0x2995
+    0x2995, // <- 0x2996
+    0x2997, // <- 0x2997
+    0x2997, // <- 0x2998
+    0x29d8, // <- 0x29d8
+    0x29d8, // <- 0x29d9
+    0x29da, // <- 0x29da
+    0x29da, // <- 0x29db
+    0x29fc, // <- 0x29fc
+    0x29fc, // <- 0x29fd
+    0x2e22, // <- 0x2e22
+    0x2e22, // <- 0x2e23
+    0x2e24, // <- 0x2e24
+    0x2e24, // <- 0x2e25
+    0x2e26, // <- 0x2e26
+    0x2e26, // <- 0x2e27
+    0x2e28, // <- 0x2e28
+    0x2e28, // <- 0x2e29
+    0x2e55, // <- 0x2e55
+    0x2e55, // <- 0x2e56
+    0x2e57, // <- 0x2e57
+    0x2e57, // <- 0x2e58
+    0x2e59, // <- 0x2e59
+    0x2e59, // <- 0x2e5a
+    0x2e5b, // <- 0x2e5b
+    0x2e5b, // <- 0x2e5c
+    0x3008, // <- 0x3008
+    0x3008, // <- 0x3009
+    0x300a, // <- 0x300a
+    0x300a, // <- 0x300b
+    0x300c, // <- 0x300c
+    0x300c, // <- 0x300d
+    0x300e, // <- 0x300e
+    0x300e, // <- 0x300f
+    0x3010, // <- 0x3010
+    0x3010, // <- 0x3011
+    0x3014, // <- 0x3014
+    0x3014, // <- 0x3015
+    0x3016, // <- 0x3016
+    0x3016, // <- 0x3017
+    0x3018, // <- 0x3018
+    0x3018, // <- 0x3019
+    0x301a, // <- 0x301a
+    0x301a, // <- 0x301b
+    0xfe59, // <- 0xfe59
+    0xfe59, // <- 0xfe5a
+    0xfe5b, // <- 0xfe5b
+    0xfe5b, // <- 0xfe5c
+    0xfe5d, // <- 0xfe5d
+    0xfe5d, // <- 0xfe5e
+    0xff08, // <- 0xff08
+    0xff08, // <- 0xff09
+    0xff3b, // <- 0xff3b
+    0xff3b, // <- 0xff3d
+    0xff5b, // <- 0xff5b
+    0xff5b, // <- 0xff5d
+    0xff5f, // <- 0xff5f
+    0xff5f, // <- 0xff60
+    0xff62, // <- 0xff62
+    0xff62, // <- 0xff63
+];
+pub static VERTICAL_ORIENTATION_KEY: [(u32, u32); 176] = [
+    (0xa7, 0xa8),
+    (0xa9, 0xaa),
+    (0xae, 0xaf),
+    (0xb1, 0xb2),
+    (0xbc, 0xbf),
+    (0xd7, 0xd8),
+    (0xf7, 0xf8),
+    (0x2ea, 0x2ec),
+    (0x1100, 0x1200),
+    (0x1401, 0x1680),
+    (0x18b0, 0x1900),
+    (0x2016, 0x2017),
+    (0x2020, 0x2022),
+    (0x2030, 0x2032),
+    (0x203b, 0x203d),
+    (0x2042, 0x2043),
+    (0x2047, 0x204a),
+    (0x2051, 0x2052),
+    (0x2065, 0x2066),
+    (0x20dd, 0x20e1),
+    (0x20e2, 0x20e5),
+    (0x2100, 0x2102),
+    (0x2103, 0x210a),
+    (0x210f, 0x2110),
+    (0x2113, 0x2115),
+    (0x2116, 0x2118),
+    (0x211e, 0x2124),
+    (0x2125, 0x2126),
+    (0x2127, 0x2128),
+    (0x2129, 0x212a),
+    (0x212e, 0x212f),
+    (0x2135, 0x2140),
+    (0x2145, 0x214b),
+    (0x214c, 0x214e),
+    (0x214f, 0x218a),
+    (0x218c, 0x2190),
+    (0x221e, 0x221f),
+    (0x2234, 0x2236),
+    (0x2300, 0x2308),
+    (0x230c, 0x2320),
+    (0x2324, 0x2329),
+    (0x2329, 0x232b),
+    (0x232b, 0x232c),
+    (0x237d, 0x239b),
+    (0x23be, 0x23ce),
+    (0x23cf, 0x23d0),
+    (0x23d1, 0x23dc),
+    (0x23e2, 0x2423),
+    (0x2424, 0x2500),
+    (0x25a0, 0x261a),
+    (0x2620, 0x2768),
+    (0x2776, 0x2794),
+    (0x2b12, 0x2b30),
+    (0x2b50, 0x2b5a),
+    (0x2b97, 0x2b98),
+    (0x2bb8, 0x2bd2),
+    (0x2bd3, 0x2bec),
+    (0x2bf0, 0x2c00),
+    (0x2e50, 0x2e52),
+    (0x2e80, 0x3001),
+    (0x3001, 0x3003),
+    (0x3003, 0x3008),
+    (0x3008, 0x3012),
+    (0x3012, 0x3014),
+    (0x3014, 0x3020),
+    (0x3020, 0x3030),
+    (0x3030, 0x3031),
+    (0x3031, 0x3041),
+    (0x3041, 0x3042),
+    (0x3042, 0x3043),
+    (0x3043, 0x3044),
+    (0x3044, 0x3045),
+    (0x3045, 0x3046),
+    (0x3046, 0x3047),
+    (0x3047, 0x3048),
+    (0x3048, 0x3049),
+    (0x3049, 0x304a),
+    (0x304a, 0x3063),
+    (0x3063, 0x3064),
+    (0x3064, 0x3083),
+    (0x3083, 0x3084),
+    (0x3084, 0x3085),
+    (0x3085, 0x3086),
+    (0x3086, 0x3087),
+    (0x3087, 0x3088),
+    (0x3088, 0x308e),
+    (0x308e, 0x308f),
+    (0x308f, 0x3095),
+    (0x3095, 0x3097),
+    (0x3097, 0x309b),
+    (0x309b, 0x309d),
+    (0x309d, 0x30a0),
+    (0x30a0, 0x30a1),
+    (0x30a1, 0x30a2),
+    (0x30a2, 0x30a3),
+    (0x30a3, 0x30a4),
+    (0x30a4, 0x30a5),
+    (0x30a5, 0x30a6),
+    (0x30a6, 0x30a7),
+    (0x30a7, 0x30a8),
+    (0x30a8, 0x30a9),
+    (0x30a9, 0x30aa),
+    (0x30aa, 0x30c3),
+    (0x30c3, 0x30c4),
+    (0x30c4, 0x30e3),
+    (0x30e3, 0x30e4),
+    (0x30e4, 0x30e5),
+    (0x30e5, 0x30e6),
+    (0x30e6, 0x30e7),
+    (0x30e7, 0x30e8),
+    (0x30e8, 0x30ee),
+    (0x30ee, 0x30ef),
+    (0x30ef, 0x30f5),
+    (0x30f5, 0x30f7),
+    (0x30f7, 0x30fc),
+    (0x30fc, 0x30fd),
+    (0x30fd, 0x3127),
+    (0x3127, 0x3128),
+    (0x3128, 0x31f0),
+    (0x31f0, 0x3200),
+    (0x3200, 0x32ff),
+    (0x32ff, 0x3358),
+    (0x3358, 0x337b),
+    (0x337b, 0x3380),
+    (0x3380, 0xa4d0),
+    (0xa960, 0xa980),
+    (0xac00, 0xd800),
+    (0xe000, 0xfb00),
+    (0xfe10, 0xfe20),
+    (0xfe30, 0xfe49),
+    (0xfe50, 0xfe53),
+    (0xfe53, 0xfe58),
+    (0xfe59, 0xfe5f),
+    (0xfe5f, 0xfe63),
+    (0xfe67, 0xfe70),
+    (0xff01, 0xff02),
+    (0xff02, 0xff08),
+    (0xff08, 0xff0a),
+    (0xff0a, 0xff0c),
+    (0xff0c, 0xff0d),
+    (0xff0e, 0xff0f),
+    (0xff0f, 0xff1a),
+    (0xff1a, 0xff1c),
+    (0xff1f, 0xff20),
+    (0xff20, 0xff3b),
+    (0xff3b, 0xff3c),
+    (0xff3c, 0xff3d),
+    (0xff3d, 0xff3e),
+    (0xff3e, 0xff3f),
+    (0xff3f, 0xff40),
+    (0xff40, 0xff5b),
+    (0xff5b, 0xff61),
+    (0xffe0, 0xffe3),
+    (0xffe3, 0xffe4),
+    (0xffe4, 0xffe8),
+    (0xfff0, 0xfff9),
+    (0xfffc, 0xfffe),
+    (0x10980, 0x109a0),
+    (0x11580, 0x11600),
+    (0x11a00, 0x11ac0),
+    (0x13000, 0x13460),
+    (0x14400, 0x14680),
+    (0x16fe0, 0x18d80),
+    (0x1aff0, 0x1b300),
+    (0x1cf00, 0x1cfd0),
+    (0x1d000, 0x1d200),
+    (0x1d2e0, 0x1d380),
+    (0x1d800, 0x1dab0),
+    (0x1f000, 0x1f200),
+    (0x1f200, 0x1f202),
+    (0x1f202, 0x1f800),
+    (0x1f900, 0x1fb00),
+    (0x20000, 0x2fffe),
+    (0x30000, 0x3fffe),
+    (0xf0000, 0xffffe),
+    (0x100000, 0x10fffe),
+];
+pub static VERTICAL_ORIENTATION_VAL: [VerticalOrientation; 176] = [
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedRotated,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::TransformedUpright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+    VerticalOrientation::Upright,
+];
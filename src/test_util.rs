@@ -0,0 +1,124 @@
+//! Fixtures shared by the unit tests.
+//!
+//! The fonts are the ones bundled in testdata/fonts; see the README there.
+
+// Some fixtures are only used by tests of optional features.
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use pathfinder_geometry::vector::{vec2f, Vector2F};
+
+use crate::shaper::{Direction, ShapeParams, ShapedGlyph, ShapedRun, Shaper};
+use crate::{Font, FontCollection, FontCollectionBuilder, FontRef};
+
+/// Covers Latin and Arabic, and U+2074 but not the Arabic presentation forms.
+pub(crate) const MADA: &str = "Mada-VF.ttf";
+/// Covers Latin and Arabic, and the Arabic presentation forms but not U+2074.
+pub(crate) const ESTEDAD: &str = "Estedad-VF.ttf";
+
+pub(crate) fn fonts_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/fonts")
+}
+
+pub(crate) fn font_path(name: &str) -> PathBuf {
+    fonts_dir().join(name)
+}
+
+pub(crate) fn font_bytes(name: &str) -> Vec<u8> {
+    fs::read(font_path(name)).unwrap()
+}
+
+/// A font loaded from bytes, without shared data.
+pub(crate) fn font_ref(name: &str) -> FontRef {
+    FontRef::new(Font::from_bytes(Arc::new(font_bytes(name)), 0).unwrap())
+}
+
+/// A collection with a family for each font, in order.
+pub(crate) fn collection(names: &[&str]) -> FontCollection {
+    let mut builder = FontCollectionBuilder::new();
+    for name in names {
+        builder.add_path(font_path(name)).unwrap();
+    }
+    builder.build()
+}
+
+pub(crate) fn read_u16(data: &[u8], offset: usize) -> usize {
+    u16::from_be_bytes([data[offset], data[offset + 1]]) as usize
+}
+
+pub(crate) fn read_u32(data: &[u8], offset: usize) -> usize {
+    u32::from_be_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ]) as usize
+}
+
+/// Put fonts together into a collection file.
+pub(crate) fn make_collection(fonts: &[Vec<u8>]) -> Vec<u8> {
+    let dir_len = |font: &[u8]| 12 + 16 * read_u16(font, 4);
+    let mut out = b"ttcf\x00\x01\x00\x00".to_vec();
+    out.extend((fonts.len() as u32).to_be_bytes());
+    let mut offset = 12 + 4 * fonts.len();
+    for font in fonts {
+        out.extend((offset as u32).to_be_bytes());
+        offset += dir_len(font);
+    }
+    let tables_start = offset;
+    let mut tables = Vec::new();
+    for font in fonts {
+        let mut dir = font[..dir_len(font)].to_vec();
+        for record in (12..dir.len()).step_by(16) {
+            let table_offset = read_u32(font, record + 8);
+            let len = read_u32(font, record + 12);
+            let new_offset = (tables_start + tables.len()) as u32;
+            dir[record + 8..record + 12].copy_from_slice(&new_offset.to_be_bytes());
+            tables.extend_from_slice(&font[table_offset..table_offset + len]);
+            tables.resize(tables.len().next_multiple_of(4), 0);
+        }
+        out.extend(dir);
+    }
+    out.extend(tables);
+    out
+}
+
+/// The advance of every character with `FixedShaper`.
+pub(crate) const ADVANCE: f32 = 10.0;
+
+/// Gives every character its code point as glyph id and the same advance,
+/// so results don't depend on the font's metrics or coverage.
+pub(crate) struct FixedShaper;
+
+impl Shaper for FixedShaper {
+    fn shape(&self, text: &str, params: &ShapeParams) -> ShapedRun {
+        let step = if params.direction.is_vertical() {
+            vec2f(0.0, ADVANCE)
+        } else {
+            vec2f(ADVANCE, 0.0)
+        };
+        let mut glyphs: Vec<ShapedGlyph> = text
+            .char_indices()
+            .map(|(ix, c)| ShapedGlyph {
+                cluster: ix as u32,
+                glyph_id: c as u32,
+                offset: Vector2F::zero(),
+                advance: step,
+                unsafe_to_break: false,
+            })
+            .collect();
+        if params.direction == Direction::RightToLeft {
+            glyphs.reverse();
+        }
+        for (i, glyph) in glyphs.iter_mut().enumerate() {
+            glyph.offset = step * i as f32;
+        }
+        ShapedRun {
+            advance: step * glyphs.len() as f32,
+            glyphs,
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! A minimal font type parsed with ttf-parser, for builds without font-kit.
+//!
+//! This provides the subset of font-kit's loader API that skribo uses, with
+//! the same names, so the rest of the crate doesn't need to know which one
+//! it has. Fonts can only be loaded from memory or from files; there is no
+//! access to system fonts and no rasterization.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use pathfinder_geometry::vector::{vec2f, Vector2F};
+
+use crate::font_data::FontData;
+
+use ttf_parser::{name_id, Face, GlyphId, Tag};
+
+/// A font, shared with the shaper by reference to its file data.
+pub struct Font {
+    data: FontData,
+    index: u32,
+    family_name: String,
+    full_name: String,
+    postscript_name: Option<String>,
+    properties: Properties,
+    metrics: Metrics,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Properties {
+    pub style: Style,
+    pub weight: Weight,
+    pub stretch: Stretch,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Style {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+/// Weight, on the CSS scale of 100 to 900.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Weight(pub f32);
+
+impl Weight {
+    pub const NORMAL: Weight = Weight(400.0);
+    pub const BOLD: Weight = Weight(700.0);
+}
+
+/// Width, as a fraction of normal.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Stretch(pub f32);
+
+impl Stretch {
+    pub const NORMAL: Stretch = Stretch(1.0);
+
+    // Indexed by usWidthClass - 1, as in font-kit.
+    const MAPPING: [f32; 9] = [0.5, 0.625, 0.75, 0.875, 1.0, 1.125, 1.25, 1.5, 2.0];
+}
+
+/// Font-wide metrics, in font units.
+#[derive(Clone, Copy, Debug)]
+pub struct Metrics {
+    pub units_per_em: u32,
+    pub ascent: f32,
+    pub descent: f32,
+    pub line_gap: f32,
+    pub underline_position: f32,
+    pub underline_thickness: f32,
+    pub cap_height: f32,
+    pub x_height: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileType {
+    Single,
+    Collection(u32),
+}
+
+#[derive(Debug)]
+pub enum FontLoadingError {
+    NoSuchFontInCollection,
+    Parse,
+    Io(io::Error),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlyphLoadingError {
+    NoSuchGlyph,
+}
+
+impl Font {
+    pub fn from_bytes(data: Arc<Vec<u8>>, index: u32) -> Result<Font, FontLoadingError> {
+        Font::from_data(FontData::from_arc_vec(data), index)
+    }
+
+    /// Load a face from shared data, which may be a mapped file.
+    pub fn from_data(data: FontData, index: u32) -> Result<Font, FontLoadingError> {
+        let face = match Face::parse(data.as_bytes(), index) {
+            Ok(face) => face,
+            Err(ttf_parser::FaceParsingError::FaceIndexOutOfBounds) => {
+                return Err(FontLoadingError::NoSuchFontInCollection)
+            }
+            Err(_) => return Err(FontLoadingError::Parse),
+        };
+        // Like FreeType, prefer the typographic family, so that all weights
+        // of a family share a name.
+        let family_name = face_name(&face, name_id::TYPOGRAPHIC_FAMILY)
+            .or_else(|| face_name(&face, name_id::FAMILY))
+            .unwrap_or_default();
+        let full_name = face_name(&face, name_id::FULL_NAME).unwrap_or_else(|| family_name.clone());
+        let postscript_name = face_name(&face, name_id::POST_SCRIPT_NAME);
+        let style = if face.is_italic() {
+            Style::Italic
+        } else if face.is_oblique() {
+            Style::Oblique
+        } else {
+            Style::Normal
+        };
+        let width_class = face.width().to_number() as usize;
+        let properties = Properties {
+            style,
+            weight: Weight(face.weight().to_number() as f32),
+            stretch: Stretch(Stretch::MAPPING[width_class.clamp(1, 9) - 1]),
+        };
+        let underline = face.underline_metrics();
+        let metrics = Metrics {
+            units_per_em: face.units_per_em() as u32,
+            ascent: face.ascender() as f32,
+            descent: face.descender() as f32,
+            line_gap: face.line_gap() as f32,
+            underline_position: underline.map(|m| m.position as f32).unwrap_or(0.0),
+            underline_thickness: underline.map(|m| m.thickness as f32).unwrap_or(0.0),
+            cap_height: face.capital_height().unwrap_or(0) as f32,
+            x_height: face.x_height().unwrap_or(0) as f32,
+        };
+        Ok(Font {
+            data,
+            index,
+            family_name,
+            full_name,
+            postscript_name,
+            properties,
+            metrics,
+        })
+    }
+
+    pub fn from_path(path: impl AsRef<Path>, index: u32) -> Result<Font, FontLoadingError> {
+        Font::from_bytes(Arc::new(fs::read(path)?), index)
+    }
+
+    pub fn analyze_bytes(data: Arc<Vec<u8>>) -> Result<FileType, FontLoadingError> {
+        match ttf_parser::fonts_in_collection(&data) {
+            Some(n) => Ok(FileType::Collection(n)),
+            None if Face::parse(&data, 0).is_ok() => Ok(FileType::Single),
+            None => Err(FontLoadingError::Parse),
+        }
+    }
+
+    pub fn analyze_path(path: impl AsRef<Path>) -> Result<FileType, FontLoadingError> {
+        Font::analyze_bytes(Arc::new(fs::read(path)?))
+    }
+
+    pub fn family_name(&self) -> String {
+        self.family_name.clone()
+    }
+
+    pub fn full_name(&self) -> String {
+        self.full_name.clone()
+    }
+
+    pub fn postscript_name(&self) -> Option<String> {
+        self.postscript_name.clone()
+    }
+
+    pub fn properties(&self) -> Properties {
+        self.properties
+    }
+
+    pub fn metrics(&self) -> Metrics {
+        self.metrics
+    }
+
+    pub fn glyph_for_char(&self, c: char) -> Option<u32> {
+        self.face().glyph_index(c).map(|id| id.0 as u32)
+    }
+
+    pub fn advance(&self, glyph_id: u32) -> Result<Vector2F, GlyphLoadingError> {
+        let advance = self
+            .face()
+            .glyph_hor_advance(GlyphId(glyph_id as u16))
+            .ok_or(GlyphLoadingError::NoSuchGlyph)?;
+        Ok(vec2f(advance as f32, 0.0))
+    }
+
+    pub fn load_font_table(&self, table_tag: u32) -> Option<Box<[u8]>> {
+        self.face().raw_face().table(Tag(table_tag)).map(Box::from)
+    }
+
+    pub fn copy_font_data(&self) -> Option<Arc<Vec<u8>>> {
+        Some(self.data.to_arc_vec())
+    }
+
+    /// The file data and the index of the face within it.
+    pub(crate) fn data_and_index(&self) -> (&FontData, u32) {
+        (&self.data, self.index)
+    }
+
+    // Parsing only reads the table directory, so we don't keep the face.
+    fn face(&self) -> Face<'_> {
+        Face::parse(self.data.as_bytes(), self.index).expect("font was validated on load")
+    }
+}
+
+fn face_name(face: &Face, id: u16) -> Option<String> {
+    face.names()
+        .into_iter()
+        .filter(|name| name.name_id == id)
+        .find_map(|name| name.to_string())
+}
+
+impl fmt::Debug for Font {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Font({})", self.full_name)
+    }
+}
+
+impl fmt::Display for FontLoadingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontLoadingError::NoSuchFontInCollection => write!(f, "no such font in the collection"),
+            FontLoadingError::Parse => write!(f, "parse error"),
+            FontLoadingError::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl Error for FontLoadingError {}
+
+impl From<io::Error> for FontLoadingError {
+    fn from(e: io::Error) -> FontLoadingError {
+        FontLoadingError::Io(e)
+    }
+}
+
+impl fmt::Display for GlyphLoadingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no such glyph")
+    }
+}
+
+impl Error for GlyphLoadingError {}
@@ -1,29 +1,26 @@
 //! Rust-native Unicode functions for harfbuzz
 
-use std::cmp::Ordering;
 use std::ffi::c_void;
 use std::ptr::null_mut;
 
 use harfbuzz::Buffer;
-use harfbuzz_sys::{
+use harfbuzz::sys::{
     hb_bool_t, hb_buffer_set_unicode_funcs, hb_codepoint_t, hb_script_t,
-    hb_unicode_combining_class_t, hb_unicode_funcs_create,
+    hb_unicode_combining_class_t, hb_unicode_funcs_create, hb_unicode_funcs_get_default,
     hb_unicode_funcs_set_combining_class_func, hb_unicode_funcs_set_compose_func,
     hb_unicode_funcs_set_decompose_func, hb_unicode_funcs_set_mirroring_func,
-    hb_unicode_funcs_set_script_func, hb_unicode_funcs_t, HB_SCRIPT_UNKNOWN,
+    hb_unicode_funcs_set_script_func, hb_unicode_funcs_t,
 };
 
 use unicode_normalization::char::{canonical_combining_class, compose};
 
-use crate::tables::{
-    CANONICAL_DECOMP_KEY, CANONICAL_DECOMP_VAL, MIRROR_KEY, MIRROR_VAL, SCRIPT_KEY, SCRIPT_VAL,
-};
+use crate::script::lookup_script;
+use crate::tables::{CANONICAL_DECOMP_KEY, CANONICAL_DECOMP_VAL, MIRROR_KEY, MIRROR_VAL};
 
 fn make_unicode_funcs() -> *mut hb_unicode_funcs_t {
-    unsafe {
-        let funcs_ptr = hb_unicode_funcs_create(null_mut());
-        funcs_ptr
-    }
+    // Functions we don't override, such as general category, come from
+    // HarfBuzz's built-in implementation.
+    unsafe { hb_unicode_funcs_create(hb_unicode_funcs_get_default()) }
 }
 
 pub fn install_unicode_funcs(buffer: &mut Buffer) {
@@ -88,7 +85,7 @@ unsafe extern "C" fn unicode_decompose(
     b: *mut hb_codepoint_t,
     _user_data: *mut c_void,
 ) -> hb_bool_t {
-    if ab >= HANGUL_SYL_BASE && ab < HANGUL_SYL_BASE + HANGUL_SYL_COUNT {
+    if (HANGUL_SYL_BASE..HANGUL_SYL_BASE + HANGUL_SYL_COUNT).contains(&ab) {
         // Decompose Hangul algorithmically.
         let syl = ab - HANGUL_SYL_BASE;
         let t = syl % HANGUL_T_COUNT;
@@ -103,7 +100,7 @@ unsafe extern "C" fn unicode_decompose(
         }
         return true.into();
     }
-    if let Ok(ix) = CANONICAL_DECOMP_KEY.binary_search(&ab.into()) {
+    if let Ok(ix) = CANONICAL_DECOMP_KEY.binary_search(&ab) {
         let (a_result, b_result) = CANONICAL_DECOMP_VAL[ix];
         *a = a_result;
         *b = b_result;
@@ -113,29 +110,6 @@ unsafe extern "C" fn unicode_decompose(
     }
 }
 
-/// Lookup the script property of a Codepoint.
-///
-/// The `hb_script_t` type is a big-endian encoding of the 4-byte string; this can also
-/// be used for other purposes such as script matching during itemization.
-///
-/// Note that for unknown script, the unknown script value is returned ("Zzzz").
-pub fn lookup_script(query: u32) -> hb_script_t {
-    let pos = SCRIPT_KEY.binary_search_by(|&(s, e)| {
-        if s > query {
-            Ordering::Greater
-        } else if e < query {
-            Ordering::Less
-        } else {
-            Ordering::Equal
-        }
-    });
-    if let Ok(ix) = pos {
-        SCRIPT_VAL[ix]
-    } else {
-        HB_SCRIPT_UNKNOWN
-    }
-}
-
 unsafe extern "C" fn unicode_script(
     _ufuncs: *mut hb_unicode_funcs_t,
     unicode: hb_codepoint_t,
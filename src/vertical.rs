@@ -0,0 +1,188 @@
+//! Orientation of characters in vertical text (UAX #50).
+
+use std::ops::Range;
+
+use crate::script::{cmp_range, lookup_script, SCRIPT_INHERITED};
+use crate::tables::{VERTICAL_ORIENTATION_KEY, VERTICAL_ORIENTATION_VAL};
+
+/// The Vertical_Orientation property of a character.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerticalOrientation {
+    Upright,
+    Rotated,
+    /// Upright, with a vertical alternate glyph if the font has one.
+    TransformedUpright,
+    /// Rotated, unless the font has a vertical alternate glyph.
+    TransformedRotated,
+}
+
+/// How the glyphs of a run are set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    /// As they appear in the code charts. Horizontal text is always upright.
+    Upright,
+    /// Rotated 90° clockwise, in vertical text. The run is laid out as
+    /// horizontal text, and each glyph should be drawn rotated about its
+    /// origin.
+    Sideways,
+}
+
+pub(crate) fn lookup_vertical_orientation(query: u32) -> VerticalOrientation {
+    match VERTICAL_ORIENTATION_KEY.binary_search_by(|&range| cmp_range(range, query)) {
+        Ok(ix) => VERTICAL_ORIENTATION_VAL[ix],
+        Err(_) => VerticalOrientation::Rotated,
+    }
+}
+
+/// A run of vertical text.
+pub(crate) struct VerticalRun {
+    pub(crate) range: Range<usize>,
+    pub(crate) orientation: Orientation,
+    /// Set horizontally in a single upright cell (tate-chu-yoko).
+    pub(crate) combine_upright: bool,
+}
+
+/// Split vertical text into upright and sideways runs, as with the CSS
+/// `text-orientation: mixed`, and the given ranges to combine upright.
+///
+/// Like Chromium, we set characters with a transformed orientation upright
+/// and rely on the `vert` feature for the alternate glyphs. Combining marks
+/// stay in the run of their base.
+pub(crate) fn vertical_runs(text: &str, combine_upright: &[Range<usize>]) -> Vec<VerticalRun> {
+    let mut combine_upright: Vec<Range<usize>> = combine_upright
+        .iter()
+        .filter(|range| !range.is_empty() && text.get(range.start..range.end).is_some())
+        .cloned()
+        .collect();
+    combine_upright.sort_by_key(|range| range.start);
+    let mut runs = Vec::new();
+    let mut start = 0;
+    for range in combine_upright {
+        // Ranges that overlap an earlier one are ignored.
+        if range.start < start {
+            continue;
+        }
+        push_mixed_runs(text, start..range.start, &mut runs);
+        start = range.end;
+        runs.push(VerticalRun {
+            range,
+            orientation: Orientation::Upright,
+            combine_upright: true,
+        });
+    }
+    push_mixed_runs(text, start..text.len(), &mut runs);
+    runs
+}
+
+fn push_mixed_runs(text: &str, range: Range<usize>, runs: &mut Vec<VerticalRun>) {
+    let first = runs.len();
+    for (ix, c) in text[range.clone()].char_indices() {
+        let start = range.start + ix;
+        let end = start + c.len_utf8();
+        let orientation = match lookup_vertical_orientation(c.into()) {
+            VerticalOrientation::Rotated => Orientation::Sideways,
+            _ => Orientation::Upright,
+        };
+        match runs[first..].last_mut() {
+            Some(run) if lookup_script(c.into()) == SCRIPT_INHERITED => run.range.end = end,
+            Some(run) if run.orientation == orientation => run.range.end = end,
+            _ => runs.push(VerticalRun {
+                range: start..end,
+                orientation,
+                combine_upright: false,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use Orientation::{Sideways, Upright};
+
+    // The text of each run, with combine upright ranges as (start, end).
+    fn runs<'a>(
+        text: &'a str,
+        combine_upright: &[(usize, usize)],
+    ) -> Vec<(&'a str, Orientation, bool)> {
+        let ranges: Vec<Range<usize>> = combine_upright
+            .iter()
+            .map(|&(start, end)| start..end)
+            .collect();
+        vertical_runs(text, &ranges)
+            .into_iter()
+            .map(|run| (&text[run.range], run.orientation, run.combine_upright))
+            .collect()
+    }
+
+    #[test]
+    fn mixed_orientation() {
+        // Latin and digits are set sideways; CJK, kana, fullwidth forms and
+        // CJK punctuation upright.
+        assert_eq!(
+            runs("日本語abc 123かなＡ、한글", &[]),
+            vec![
+                ("日本語", Upright, false),
+                ("abc 123", Sideways, false),
+                ("かなＡ、한글", Upright, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn marks_stay_with_base() {
+        // U+20DD and U+3099 are upright themselves, and U+0301 is sideways.
+        assert_eq!(
+            runs("e\u{301}\u{20dd}か\u{3099}\u{301}x", &[]),
+            vec![
+                ("e\u{301}\u{20dd}", Sideways, false),
+                ("か\u{3099}\u{301}", Upright, false),
+                ("x", Sideways, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn combine_upright_ranges() {
+        assert_eq!(
+            runs("a12b", &[(1, 3)]),
+            vec![
+                ("a", Sideways, false),
+                ("12", Upright, true),
+                ("b", Sideways, false),
+            ]
+        );
+        // Ranges may be given in any order.
+        assert_eq!(
+            runs("日12日34", &[(8, 10), (3, 5)]),
+            vec![
+                ("日", Upright, false),
+                ("12", Upright, true),
+                ("日", Upright, false),
+                ("34", Upright, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_combine_upright_ranges() {
+        let sideways = vec![("a12b", Sideways, false)];
+        // Empty, out of bounds, and not on character boundaries.
+        assert_eq!(runs("a12b", &[(2, 2)]), sideways);
+        assert_eq!(runs("a12b", &[(3, 5)]), sideways);
+        assert_eq!(
+            runs("日12", &[(1, 5)]),
+            vec![("日", Upright, false), ("12", Sideways, false)]
+        );
+        // A range overlapping an earlier one is dropped.
+        assert_eq!(
+            runs("a123b", &[(1, 3), (2, 4)]),
+            vec![
+                ("a", Sideways, false),
+                ("12", Upright, true),
+                ("3b", Sideways, false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! Tests of the pure-Rust configuration on wasm32, run with
+//! wasm-bindgen-test. See the CI workflow for how to run them.
+
+#![cfg(target_arch = "wasm32")]
+
+use std::sync::Arc;
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use skribo::{BaseDirection, FontCollectionBuilder, LayoutSession, TextStyle};
+
+const MADA: &[u8] = include_bytes!("../testdata/fonts/Mada-VF.ttf");
+
+#[wasm_bindgen_test]
+fn layout_from_bytes() {
+    let mut builder = FontCollectionBuilder::new();
+    builder.add_bytes(Arc::new(MADA.to_vec()), 0).unwrap();
+    let collection = builder.build();
+    let style = TextStyle {
+        bidi: Some(BaseDirection::Auto),
+        ..Default::default()
+    };
+    let text = "Hello مرحبا";
+    let session = LayoutSession::create(text, &style, &collection);
+    let layout = session.to_layout();
+    assert!(!layout.glyphs.is_empty());
+    assert!(layout.glyphs.iter().all(|glyph| glyph.glyph_id != 0));
+    assert!(layout.advance.x() > 0.0);
+}